@@ -0,0 +1,40 @@
+use aoc_common::{Answer, Result, Solution};
+
+fn parse_value(value: i64, full: bool) -> i64 {
+    let new_value = value / 3 - 2;
+    if new_value < 0 {
+        return 0;
+    }
+
+    if full {
+        new_value + parse_value(new_value, full)
+    } else {
+        new_value
+    }
+}
+
+fn puzzle(input: &[i64], full: bool) -> i64 {
+    input.iter().map(|x| parse_value(*x, full)).sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .split("\n")
+            .filter(|x| !x.is_empty())
+            .map(str::parse)
+            .collect::<std::result::Result<_, _>>()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(puzzle(input, false).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(puzzle(input, true).into())
+    }
+}
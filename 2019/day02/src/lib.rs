@@ -0,0 +1,335 @@
+use aoc_common::{Answer, Error, Params, Result, Solution};
+use intcode::memory::MAX_ADDRESS;
+use intcode::{parse_program, Instruction, IntcodeError, IntcodeVm, Mode, Opcode};
+
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use std::thread;
+
+const PRINT_DEBUG: bool = false;
+
+const TARGET: i64 = 19690720;
+
+fn intcode(vm: &mut IntcodeVm, noun: i64, verb: i64) -> std::result::Result<i64, IntcodeError> {
+    vm.write(1, noun)?;
+    vm.write(2, verb)?;
+    vm.run_with_inputs(&[])?;
+
+    Ok(vm.read(0))
+}
+
+/// A polynomial in the noun and verb, as coefficients keyed by the powers of
+/// each.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Poly(BTreeMap<(u32, u32), i64>);
+
+impl Poly {
+    fn constant(value: i64) -> Poly {
+        Poly::term(value, 0, 0)
+    }
+
+    fn term(coefficient: i64, noun: u32, verb: u32) -> Poly {
+        let mut terms = BTreeMap::new();
+        if coefficient != 0 {
+            terms.insert((noun, verb), coefficient);
+        }
+        Poly(terms)
+    }
+
+    fn as_constant(&self) -> Option<i64> {
+        match self.0.iter().next() {
+            None => Some(0),
+            Some((&(0, 0), &value)) if self.0.len() == 1 => Some(value),
+            _ => None,
+        }
+    }
+
+    fn insert(&mut self, powers: (u32, u32), coefficient: i64) -> Option<()> {
+        let sum = self
+            .0
+            .get(&powers)
+            .copied()
+            .unwrap_or(0)
+            .checked_add(coefficient)?;
+        if sum == 0 {
+            self.0.remove(&powers);
+        } else {
+            self.0.insert(powers, sum);
+        }
+        Some(())
+    }
+
+    fn add(&self, other: &Poly) -> Option<Poly> {
+        let mut sum = self.clone();
+        for (&powers, &coefficient) in &other.0 {
+            sum.insert(powers, coefficient)?;
+        }
+        Some(sum)
+    }
+
+    fn mul(&self, other: &Poly) -> Option<Poly> {
+        let mut product = Poly::default();
+        for (&(n1, v1), &c1) in &self.0 {
+            for (&(n2, v2), &c2) in &other.0 {
+                product.insert((n1 + n2, v1 + v2), c1.checked_mul(c2)?)?;
+            }
+        }
+        Some(product)
+    }
+
+    /// With the noun fixed, the coefficients of the remaining polynomial in
+    /// the verb, lowest power first and without trailing zeros.
+    fn in_verb(&self, noun: i64) -> Option<Vec<i64>> {
+        let mut coefficients: Vec<i64> = Vec::new();
+        for (&(n, v), &c) in &self.0 {
+            let v = v as usize;
+            if coefficients.len() <= v {
+                coefficients.resize(v + 1, 0);
+            }
+            let term = c.checked_mul(noun.checked_pow(n)?)?;
+            coefficients[v] = coefficients[v].checked_add(term)?;
+        }
+        while coefficients.last() == Some(&0) {
+            coefficients.pop();
+        }
+        Some(coefficients)
+    }
+}
+
+/// Runs the program with the noun and verb left as variables and returns
+/// what ends up in address 0. Only straight-line add/mul code can be
+/// followed this way; anything else gives `None`.
+///
+/// A cell holding `None` was computed from an address that depended on the
+/// inputs. That's fine as long as nothing that matters ever reads it.
+fn symbolic(program: &[i64]) -> Option<Poly> {
+    let mut memory: Vec<Option<Poly>> = program.iter().map(|&v| Some(Poly::constant(v))).collect();
+    memory[1] = Some(Poly::term(1, 1, 0));
+    memory[2] = Some(Poly::term(1, 0, 1));
+
+    let concrete = |memory: &[Option<Poly>], address: usize| match memory.get(address) {
+        Some(value) => value.as_ref()?.as_constant(),
+        None => Some(0),
+    };
+
+    let mut pc = 0;
+    loop {
+        let instruction = Instruction::decode(concrete(&memory, pc)?)?;
+        let parameter = |memory: &[Option<Poly>], index: usize| -> Option<Option<Poly>> {
+            let raw = concrete(memory, pc + 1 + index);
+            match instruction.modes[index] {
+                Mode::Immediate => Some(Some(Poly::constant(raw?))),
+                Mode::Position => match raw {
+                    // Some later write may well overwrite whatever this
+                    // produces, so it isn't a failure yet.
+                    None => Some(None),
+                    Some(address) => {
+                        let address = usize::try_from(address).ok()?;
+                        Some(
+                            memory
+                                .get(address)
+                                .cloned()
+                                .unwrap_or(Some(Poly::default())),
+                        )
+                    }
+                },
+                Mode::Relative => None,
+            }
+        };
+
+        match instruction.opcode {
+            Opcode::Add | Opcode::Multiply => {
+                let a = parameter(&memory, 0)?;
+                let b = parameter(&memory, 1)?;
+                let value = match (a, b) {
+                    (Some(a), Some(b)) if instruction.opcode == Opcode::Add => Some(a.add(&b)?),
+                    (Some(a), Some(b)) => Some(a.mul(&b)?),
+                    _ => None,
+                };
+
+                if instruction.modes[2] != Mode::Position {
+                    return None;
+                }
+                // The real run would fail here; leave that to the brute force.
+                let target = usize::try_from(concrete(&memory, pc + 3)?).ok()?;
+                if target >= MAX_ADDRESS {
+                    return None;
+                }
+                if target >= memory.len() {
+                    memory.resize(target + 1, Some(Poly::default()));
+                }
+                memory[target] = value;
+                pc += 4;
+            }
+            Opcode::Halt => return memory[0].clone(),
+            _ => return None,
+        }
+    }
+}
+
+/// The first noun/verb pair, in order, for which `poly` comes out as
+/// `target`. Nouns whose arithmetic overflows are skipped.
+fn solve(
+    poly: &Poly,
+    target: i64,
+    nouns: &RangeInclusive<i64>,
+    verbs: &RangeInclusive<i64>,
+) -> Option<(i64, i64)> {
+    for noun in nouns.clone() {
+        let Some(coefficients) = poly.in_verb(noun) else {
+            continue;
+        };
+        match coefficients[..] {
+            [] | [_] => {
+                let constant = coefficients.first().copied().unwrap_or(0);
+                if constant == target && !verbs.is_empty() {
+                    return Some((noun, *verbs.start()));
+                }
+            }
+            [constant, slope] => {
+                let Some(rest) = target.checked_sub(constant) else {
+                    continue;
+                };
+                if rest.checked_rem(slope) == Some(0) {
+                    let verb = rest / slope;
+                    if verbs.contains(&verb) {
+                        return Some((noun, verb));
+                    }
+                }
+            }
+            // Higher powers of the verb are rare enough to just try them all.
+            _ => {
+                let verb = verbs.clone().find(|&verb| {
+                    let value = coefficients
+                        .iter()
+                        .rev()
+                        .try_fold(0i64, |sum, &c| sum.checked_mul(verb)?.checked_add(c));
+                    value == Some(target)
+                });
+                if let Some(verb) = verb {
+                    return Some((noun, verb));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Tries every pair, splitting the nouns between threads.
+fn brute_force(
+    program: &[i64],
+    target: i64,
+    nouns: &RangeInclusive<i64>,
+    verbs: &RangeInclusive<i64>,
+) -> Result<Option<(i64, i64)>> {
+    let nouns: Vec<i64> = nouns.clone().collect();
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = nouns.len().div_ceil(threads).max(1);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = nouns
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    // Restored VMs share pages with the snapshot; a run only
+                    // copies the pages it writes to.
+                    let mut vm = IntcodeVm::new(program);
+                    let initial = vm.snapshot();
+
+                    for &noun in chunk {
+                        for verb in verbs.clone() {
+                            // Some noun/verb pairs turn the program into
+                            // garbage; those just aren't the answer.
+                            vm.restore(&initial);
+                            let output = intcode(&mut vm, noun, verb);
+
+                            if PRINT_DEBUG {
+                                println!("{} {} = {:?}", noun, verb, output);
+                            }
+
+                            if output == Ok(target) {
+                                return Some((noun, verb));
+                            }
+                        }
+                    }
+
+                    None
+                })
+            })
+            .collect();
+
+        // Chunks are in noun order, so the first hit is the first pair.
+        let pairs = handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .map_err(|_| Error::solve("A noun/verb search thread panicked"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(pairs.into_iter().flatten().next())
+    })
+}
+
+fn part1(input: &Gravity) -> Result<i64> {
+    Ok(intcode(&mut IntcodeVm::new(&input.program), 12, 2)?)
+}
+
+fn part2(input: &Gravity) -> Result<i64> {
+    // Reading address `noun` or `verb` could fail in the real program for
+    // negative inputs, which the symbolic run can't see.
+    let symbolic = match input.nouns.start().min(input.verbs.start()) {
+        0.. => symbolic(&input.program),
+        _ => None,
+    };
+
+    let pair = match &symbolic {
+        Some(poly) => solve(poly, input.target, &input.nouns, &input.verbs),
+        None => brute_force(&input.program, input.target, &input.nouns, &input.verbs)?,
+    };
+
+    let (noun, verb) =
+        pair.ok_or_else(|| Error::solve(format!("No noun and verb give {}", input.target)))?;
+    100i64
+        .checked_mul(noun)
+        .and_then(|n| n.checked_add(verb))
+        .ok_or_else(|| Error::solve(format!("Noun {noun} and verb {verb} overflow the answer")))
+}
+
+pub struct Gravity {
+    program: Vec<i64>,
+    target: i64,
+    nouns: RangeInclusive<i64>,
+    verbs: RangeInclusive<i64>,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Gravity;
+    const PARAMS: &'static [&'static str] =
+        &["target", "min_noun", "max_noun", "min_verb", "max_verb"];
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let program = parse_program(input)?;
+        if program.len() < 3 {
+            return Err(Error::parse("Program is too short to take a noun and verb"));
+        }
+
+        Ok(Gravity {
+            program,
+            target: params.get("target", TARGET)?,
+            nouns: params.get("min_noun", 0)?..=params.get("max_noun", 99)?,
+            verbs: params.get("min_verb", 0)?..=params.get("max_verb", 99)?,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
@@ -1,54 +1,46 @@
+use aoc_common::{Answer, Error, Result, Solution};
 use std::collections::HashMap;
 
-use std::env;
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
-
 #[derive(Debug)]
-enum Operation {
+pub enum Operation {
     Right(i32),
     Left(i32),
     Up(i32),
     Down(i32),
 }
 
-fn parse_file(filename: &str) -> String {
-    let path = Path::new(filename);
-
-    let mut file = match File::open(&path) {
-        Err(_) => panic!("Couldn't open file"),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-    match file.read_to_string(&mut s) {
-        Err(_) => panic!("Couldn't read file"),
-        Ok(_) => (),
-    };
-
-    s
-}
-
-fn parse_operation(input: &str) -> Operation {
-    let value: i32 = input[1..].parse().unwrap();
-
-    match input.chars().nth(0).unwrap() {
-        'U' => Operation::Up(value),
-        'D' => Operation::Down(value),
-        'R' => Operation::Right(value),
-        'L' => Operation::Left(value),
-        _ => panic!("Unexpected Operation"),
+fn parse_operation(input: &str) -> Result<Operation> {
+    let mut chars = input.chars();
+    let direction = chars.next();
+    let value: i32 = chars.as_str().parse()?;
+
+    match direction {
+        Some('U') => Ok(Operation::Up(value)),
+        Some('D') => Ok(Operation::Down(value)),
+        Some('R') => Ok(Operation::Right(value)),
+        Some('L') => Ok(Operation::Left(value)),
+        _ => Err(Error::parse(format!("Unexpected operation {}", input))),
     }
 }
 
-fn puzzle(input: &String) -> (i32, i32) {
-    let inputs: Vec<Vec<Operation>> = input
+fn parse_input(s: &str) -> Result<Vec<Vec<Operation>>> {
+    let inputs: Vec<Vec<Operation>> = s
         .split("\n")
         .filter(|s| !s.is_empty())
-        .map(|s| s.split(",").map(|v| parse_operation(v)).collect())
-        .collect();
+        .map(|s| s.split(",").map(parse_operation).collect())
+        .collect::<Result<_>>()?;
+
+    if inputs.len() != 2 {
+        return Err(Error::parse(format!(
+            "Expected two wires, found {}",
+            inputs.len()
+        )));
+    }
+
+    Ok(inputs)
+}
 
+fn puzzle(inputs: &[Vec<Operation>]) -> (i32, i32) {
     let mut stored: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
     let mut shortest_distance = i32::MAX;
     let mut shortest_signal = i32::MAX;
@@ -149,15 +141,20 @@ fn puzzle(input: &String) -> (i32, i32) {
     (shortest_distance, shortest_signal)
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        panic!("Incorrect Usage");
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<Operation>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
     }
 
-    let input = parse_file(args.get(1).unwrap());
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(puzzle(input).0.into())
+    }
 
-    let (p1, p2) = puzzle(&input);
-    println!("Part 1: {p1}");
-    println!("Part 2: {p2}");
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(puzzle(input).1.into())
+    }
 }
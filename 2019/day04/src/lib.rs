@@ -1,25 +1,5 @@
+use aoc_common::{Answer, Error, Result, Solution};
 use std::collections::HashMap;
-use std::env;
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
-
-fn parse_file(filename: &str) -> String {
-    let path = Path::new(filename);
-
-    let mut file = match File::open(&path) {
-        Err(_) => panic!("Couldn't open file"),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-    match file.read_to_string(&mut s) {
-        Err(_) => panic!("Couldn't read file"),
-        Ok(_) => (),
-    };
-
-    s
-}
 
 fn is_valid_num(num: i32, allow_repeated: bool) -> bool {
     assert!(num >= 100000);
@@ -61,16 +41,35 @@ fn is_valid_num(num: i32, allow_repeated: bool) -> bool {
     double
 }
 
-fn part1() -> (i32, i32) {
-    (165432..=707912)
+fn parse_input(s: &str) -> Result<(i32, i32)> {
+    let (low, high) = s
+        .trim()
+        .split_once('-')
+        .ok_or_else(|| Error::parse("Expected a range like 123456-654321"))?;
+
+    Ok((low.parse()?, high.parse()?))
+}
+
+fn puzzle(low: i32, high: i32) -> (i32, i32) {
+    (low..=high)
         .map(|v| (is_valid_num(v, true) as i32, is_valid_num(v, false) as i32))
         .fold((0, 0), |(sum_a, sum_b), (a, b)| (sum_a + a, sum_b + b))
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = (i32, i32);
 
-    let (p1, p2) = part1();
-    println!("Part 1: {p1}");
-    println!("Part 2: {p2}");
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(&(low, high): &Self::Input) -> Result<Answer> {
+        Ok(puzzle(low, high).0.into())
+    }
+
+    fn part2(&(low, high): &Self::Input) -> Result<Answer> {
+        Ok(puzzle(low, high).1.into())
+    }
 }
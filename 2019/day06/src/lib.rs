@@ -1,8 +1,7 @@
+use aoc_common::{Answer, Error, Result, Solution};
 use std::collections::BTreeMap;
-use std::env;
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
+
+const DEBUG_PRINT: bool = false;
 
 #[derive(Debug)]
 struct Object {
@@ -12,40 +11,27 @@ struct Object {
 }
 
 #[derive(Debug)]
-struct Orbit {
-    pub objects: Vec<Object>,
-    pub lookup: BTreeMap<String, usize>,
+pub struct Orbit {
+    objects: Vec<Object>,
+    lookup: BTreeMap<String, usize>,
 }
 
-fn parse_file(filename: &str) -> Orbit {
-    let path = Path::new(filename);
-
-    let mut file = match File::open(&path) {
-        Err(_) => panic!("Couldn't open file"),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-    match file.read_to_string(&mut s) {
-        Err(_) => panic!("Couldn't read file"),
-        Ok(_) => (),
-    };
-
-    let items: Vec<Vec<&str>> = s
+fn parse_file(s: &str) -> Result<Orbit> {
+    let items: Vec<(&str, &str)> = s
         .split("\n")
         .filter(|s| !s.is_empty())
-        .map(|s| s.split(")").collect())
-        .collect();
+        .map(|s| {
+            s.split_once(')')
+                .ok_or_else(|| Error::parse(format!("Invalid orbit {}", s)))
+        })
+        .collect::<Result<_>>()?;
 
     let mut orbit: Orbit = Orbit {
         objects: Vec::new(),
         lookup: BTreeMap::new(),
     };
 
-    for item in items {
-        let parent = item[0];
-        let child = item[1];
-
+    for (parent, child) in items {
         if !orbit.lookup.contains_key(parent) {
             let parent_obj = Object {
                 name: parent.to_string(),
@@ -54,12 +40,14 @@ fn parse_file(filename: &str) -> Orbit {
             };
 
             let index = orbit.objects.len();
-            println!("Adding Parent: {parent}: {index}");
+            if DEBUG_PRINT {
+                println!("Adding Parent: {parent}: {index}");
+            }
             orbit.objects.push(parent_obj);
             orbit.lookup.insert(parent.to_string(), index);
         }
 
-        let parent_index = orbit.lookup.get(parent).unwrap();
+        let parent_index = &orbit.lookup[parent];
 
         let child_obj = Object {
             name: child.to_string(),
@@ -71,7 +59,9 @@ fn parse_file(filename: &str) -> Orbit {
         if let Some(x) = orbit.lookup.get(child) {
             index = *x;
         } else {
-            println!("Adding Child: {child}: {index}");
+            if DEBUG_PRINT {
+                println!("Adding Child: {child}: {index}");
+            }
             orbit.objects.push(child_obj);
             orbit.lookup.insert(child.to_string(), index);
         }
@@ -80,7 +70,9 @@ fn parse_file(filename: &str) -> Orbit {
             {
                 let p = orbit.objects.get_mut(*id).unwrap();
                 p.children.push(index);
-                println!("Adding Child -> parent | {}: {}", p.name, child);
+                if DEBUG_PRINT {
+                    println!("Adding Child -> parent | {}: {}", p.name, child);
+                }
             }
 
             {
@@ -90,7 +82,7 @@ fn parse_file(filename: &str) -> Orbit {
         }
     }
 
-    orbit
+    Ok(orbit)
 }
 
 fn count_orbits(obj: &Object, orbit: &Orbit, depth: u64) -> u64 {
@@ -104,64 +96,71 @@ fn count_orbits(obj: &Object, orbit: &Orbit, depth: u64) -> u64 {
     count
 }
 
-fn part1(orbit: &Orbit) -> u64 {
-    let parent = &orbit.objects[*orbit.lookup.get("COM").unwrap()];
-    return count_orbits(&parent, orbit, 0);
+fn part1(orbit: &Orbit) -> Result<u64> {
+    let com = orbit
+        .lookup
+        .get("COM")
+        .ok_or_else(|| Error::solve("No COM"))?;
+    Ok(count_orbits(&orbit.objects[*com], orbit, 0))
 }
 
-fn get_parent_id(name: &str, orbit: &Orbit) -> usize {
-    let obj = orbit.objects.get(*orbit.lookup.get(name).unwrap()).unwrap();
-    if let Some(x) = obj.parent {
-        x
-    } else {
-        panic!("No parent");
-    }
+fn get_parent_id(name: &str, orbit: &Orbit) -> Result<usize> {
+    let id = orbit
+        .lookup
+        .get(name)
+        .ok_or_else(|| Error::solve(format!("No object {}", name)))?;
+    orbit.objects[*id]
+        .parent
+        .ok_or_else(|| Error::solve(format!("{} has no parent", name)))
 }
 
-fn can_reach(current_id: usize, target_id: usize, orbit: &Orbit) -> Result<usize, ()> {
+fn can_reach(current_id: usize, target_id: usize, orbit: &Orbit) -> Option<usize> {
     let obj = orbit.objects.get(current_id).unwrap();
     if current_id == target_id {
-        return Ok(0);
+        return Some(0);
     }
 
     for child in obj.children.iter() {
         let result = can_reach(*child, target_id, orbit);
-        if let Ok(x) = result {
-            return Ok(x + 1);
+        if let Some(x) = result {
+            return Some(x + 1);
         }
     }
 
-    Err(())
+    None
 }
 
-fn part2(orbit: &Orbit) -> usize {
-    let parent_you = get_parent_id("YOU", orbit);
-    let parent_san = get_parent_id("SAN", orbit);
+fn part2(orbit: &Orbit) -> Result<usize> {
+    let parent_you = get_parent_id("YOU", orbit)?;
+    let parent_san = get_parent_id("SAN", orbit)?;
 
     let mut current_parent = parent_you;
     let mut distance = 0;
     loop {
         let reachable = can_reach(current_parent, parent_san, orbit);
-        if let Ok(x) = reachable {
-            return distance + x;
+        if let Some(x) = reachable {
+            return Ok(distance + x);
         } else {
             distance += 1;
-            current_parent = get_parent_id(&orbit.objects.get(current_parent).unwrap().name, orbit);
+            current_parent = get_parent_id(&orbit.objects[current_parent].name, orbit)?;
         }
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        panic!("Incorrect Usage");
-    }
+pub struct Day06;
 
-    let input = parse_file(args.get(1).unwrap());
+impl Solution for Day06 {
+    type Input = Orbit;
 
-    let p1 = part1(&input);
-    println!("Part 1: {p1}");
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_file(input)
+    }
 
-    let p2 = part2(&input);
-    println!("Part 2: {p2}");
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
 }
@@ -0,0 +1,36 @@
+//! Prints the best phase sequences for both wirings, a line each like
+//! `43210 from phases 4,3,2,1,0`. Takes the input file and optionally
+//! `--top K` plus the puzzle's `--phases1`, `--phases2` and `--amps`.
+use aoc2019_day07::Day07;
+use aoc_common::{read_input, Params, Solution};
+
+use std::env;
+use std::process;
+
+fn report(title: &str, results: &[(Vec<i64>, i64)]) {
+    println!("{title}:");
+    for (phases, signal) in results {
+        let phases: Vec<String> = phases.iter().map(i64::to_string).collect();
+        println!("  {signal} from phases {}", phases.join(","));
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 2 || !args.len().is_multiple_of(2) {
+        eprintln!("Usage: phases INPUT [--top K] [--phases1 P] [--phases2 P] [--amps N]");
+        process::exit(2);
+    }
+
+    let mut params = Params::new();
+    for pair in args[2..].chunks(2) {
+        params.set(pair[0].trim_start_matches("--"), pair[1].as_str());
+    }
+    let top = params.get("top", 5)?;
+
+    let amplifiers = Day07::parse_with(&read_input(&args[1])?, &params)?;
+    report("Chain", &amplifiers.chain(top)?);
+    report("Feedback loop", &amplifiers.ring(top)?);
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use aoc_common::{Answer, Error, Params, Result, Solution};
+use intcode::{parse_program, IntcodeVm, Network, NetworkState, Topology};
+
+use std::thread;
+
+/// Runs one amplifier per phase, wired up as `topology`, and returns the
+/// last signal out of the final amplifier.
+fn amplify(input: &[i64], phases: &[i64], topology: Topology) -> Result<i64> {
+    let amps = phases
+        .iter()
+        .map(|&phase| {
+            let mut amp = IntcodeVm::new(input);
+            amp.push_input(phase);
+            amp
+        })
+        .collect();
+
+    let mut network = Network::new(amps, topology);
+    network.send(0, 0);
+    match network.run()? {
+        NetworkState::Halted => {}
+        state => {
+            return Err(Error::solve(format!(
+                "Amplifiers {phases:?} stopped without halting: {state:?}"
+            )))
+        }
+    }
+
+    network
+        .last_output(phases.len() - 1)
+        .ok_or_else(|| Error::solve(format!("Amplifiers {phases:?} produced no signal")))
+}
+
+/// Every ordered choice of `count` distinct values from `set`, in
+/// lexicographic order of their positions in `set`.
+pub fn permutations(set: &[i64], count: usize) -> Vec<Vec<i64>> {
+    fn extend(
+        set: &[i64],
+        used: &mut [bool],
+        current: &mut Vec<i64>,
+        count: usize,
+        out: &mut Vec<Vec<i64>>,
+    ) {
+        if current.len() == count {
+            out.push(current.clone());
+            return;
+        }
+        for i in 0..set.len() {
+            if !used[i] {
+                used[i] = true;
+                current.push(set[i]);
+                extend(set, used, current, count, out);
+                current.pop();
+                used[i] = false;
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    extend(
+        set,
+        &mut vec![false; set.len()],
+        &mut Vec::new(),
+        count,
+        &mut out,
+    );
+    out
+}
+
+/// Tries every phase sequence of `amps` amplifiers drawn from `phases`,
+/// spread over all available threads. Returns the `top` best sequences
+/// with their signals, best first.
+pub fn search(
+    program: &[i64],
+    phases: &[i64],
+    amps: usize,
+    topology: Topology,
+    top: usize,
+) -> Result<Vec<(Vec<i64>, i64)>> {
+    let candidates = permutations(phases, amps);
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = candidates.len().div_ceil(threads).max(1);
+
+    let mut results = thread::scope(|scope| {
+        let handles: Vec<_> = candidates
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|phases| Ok((phases.clone(), amplify(program, phases, topology)?)))
+                        .collect::<Result<Vec<_>>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .map_err(|_| Error::solve("An amplifier search thread panicked"))?
+            })
+            .collect::<Result<Vec<_>>>()
+    })?
+    .concat();
+
+    // Ties keep the lexicographically first sequence, so results don't
+    // depend on the thread count.
+    results.sort_by(|(a, x), (b, y)| y.cmp(x).then_with(|| a.cmp(b)));
+    results.truncate(top);
+    Ok(results)
+}
+
+/// The best signal out of the chain and ring wirings, with their phase
+/// sequences drawn from `--phases1` and `--phases2`.
+pub struct Amplifiers {
+    program: Vec<i64>,
+    phases1: Vec<i64>,
+    phases2: Vec<i64>,
+    amps: usize,
+}
+
+impl Amplifiers {
+    /// The `top` best sequences with the amplifiers in a chain.
+    pub fn chain(&self, top: usize) -> Result<Vec<(Vec<i64>, i64)>> {
+        search(
+            &self.program,
+            &self.phases1,
+            self.amps,
+            Topology::Chain,
+            top,
+        )
+    }
+
+    /// The `top` best sequences with the amplifiers in a feedback loop.
+    pub fn ring(&self, top: usize) -> Result<Vec<(Vec<i64>, i64)>> {
+        search(&self.program, &self.phases2, self.amps, Topology::Ring, top)
+    }
+}
+
+fn best(results: Vec<(Vec<i64>, i64)>) -> Result<i64> {
+    results
+        .first()
+        .map(|(_, signal)| *signal)
+        .ok_or_else(|| Error::solve("No phase sequences to try"))
+}
+
+fn part1(input: &Amplifiers) -> Result<i64> {
+    best(input.chain(1)?)
+}
+
+fn part2(input: &Amplifiers) -> Result<i64> {
+    best(input.ring(1)?)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Amplifiers;
+    const PARAMS: &'static [&'static str] = &["phases1", "phases2", "amps"];
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let amplifiers = Amplifiers {
+            program: parse_program(input)?,
+            phases1: parse_program(&params.get("phases1", "0,1,2,3,4".to_string())?)?,
+            phases2: parse_program(&params.get("phases2", "5,6,7,8,9".to_string())?)?,
+            amps: params.get("amps", 5)?,
+        };
+
+        let fewest = amplifiers.phases1.len().min(amplifiers.phases2.len());
+        if amplifiers.amps == 0 || amplifiers.amps > fewest {
+            return Err(Error::Param(format!(
+                "--amps {} with only {} phases",
+                amplifiers.amps, fewest
+            )));
+        }
+
+        Ok(amplifiers)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+}
@@ -0,0 +1,30 @@
+//! Saves the decoded password image as a PNG, white on black with
+//! transparent pixels in grey. Takes the input file, the output path, and
+//! optionally `--width W --height H` for inputs other than the real one.
+use aoc2019_day08::{decode, Day08};
+use aoc_common::image::{Palette, Picture, BLACK, WHITE};
+use aoc_common::{read_input, Params, Solution};
+
+use std::env;
+use std::process;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 3 || args.len().is_multiple_of(2) {
+        eprintln!("Usage: image INPUT OUTPUT [--width W] [--height H]");
+        process::exit(2);
+    }
+
+    let mut params = Params::new();
+    for pair in args[3..].chunks(2) {
+        params.set(pair[0].trim_start_matches("--"), pair[1].as_str());
+    }
+
+    let ((width, height), input) = Day08::parse_with(&read_input(&args[1])?, &params)?;
+    let palette = Palette::new([128, 128, 128])
+        .with('0', BLACK)
+        .with('1', WHITE);
+    Picture::from_grid(&decode(&input, width, height)?, &palette, 10).save_png(&args[2])?;
+
+    Ok(())
+}
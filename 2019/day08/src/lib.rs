@@ -0,0 +1,106 @@
+use aoc_common::render::{Render, Style};
+use aoc_common::{Answer, Error, Grid, Params, Result, Solution, Vec2};
+
+/// Layers need at least one pixel, and no more than fit in an `i32`.
+fn check_size(width: i32, height: i32) -> Result<()> {
+    match width.checked_mul(height) {
+        Some(_) if width > 0 && height > 0 => Ok(()),
+        _ => Err(Error::parse(format!(
+            "Invalid image size {}x{}",
+            width, height
+        ))),
+    }
+}
+
+fn part1(input: &str, width: i32, height: i32) -> u64 {
+    let mut layers: Vec<String> = Vec::new();
+    let mut current_string: String = String::new();
+    let mut current_index = 0;
+    for i in input.chars() {
+        current_string.push(i);
+        current_index += 1;
+        if current_index == width * height {
+            layers.push(current_string);
+            current_index = 0;
+            current_string = String::new();
+        }
+    }
+
+    let (a, b) = layers
+        .iter()
+        .map(|l| {
+            let v = l.chars().fold(0, |c, v| if v == '0' { c + 1 } else { c });
+            (v, l)
+        })
+        .fold((i32::MAX, String::new()), |(m, p), (c, l)| {
+            if c < m {
+                (c, l.to_string())
+            } else {
+                (m, p)
+            }
+        })
+        .1
+        .chars()
+        .fold((0, 0), |(a, b), c| {
+            if c == '1' {
+                (a + 1, b)
+            } else if c == '2' {
+                (a, b + 1)
+            } else {
+                (a, b)
+            }
+        });
+
+    a * b
+}
+
+/// Stacks the layers into the final image: each pixel is the topmost one
+/// that isn't transparent (`2`), or `2` if they all are.
+pub fn decode(input: &str, width: i32, height: i32) -> Result<Grid<char>> {
+    check_size(width, height)?;
+    let mut image = Grid::new(width, height, '2')?;
+    let pixels = image.positions().collect::<Vec<Vec2>>();
+
+    for layer in input.as_bytes().chunks((width * height) as usize) {
+        for (pos, c) in pixels.iter().zip(layer) {
+            if image[*pos] == '2' {
+                image[*pos] = *c as char;
+            }
+        }
+    }
+
+    Ok(image)
+}
+
+fn part2(input: &str, width: i32, height: i32) -> Result<String> {
+    let image = decode(input, width, height)?;
+    let output = Render::new(&image, |c| Style::glyph(if *c == '1' { '#' } else { ' ' }))
+        .plain()
+        .to_string();
+
+    Ok(output)
+}
+
+const WIDTH: i32 = 25;
+const HEIGHT: i32 = 6;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = ((i32, i32), String);
+    const PARAMS: &'static [&'static str] = &["width", "height"];
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let size = (params.get("width", WIDTH)?, params.get("height", HEIGHT)?);
+        check_size(size.0, size.1)?;
+        Ok((size, input.trim().to_string()))
+    }
+
+    fn part1(((width, height), input): &Self::Input) -> Result<Answer> {
+        Ok(part1(input, *width, *height).into())
+    }
+
+    fn part2(((width, height), input): &Self::Input) -> Result<Answer> {
+        Ok(part2(input, *width, *height)?.into())
+    }
+}
@@ -0,0 +1,35 @@
+use aoc_common::{Answer, Error, Result, Solution};
+use intcode::{parse_program, IntcodeVm};
+
+const DEBUG_PRINT: bool = false;
+
+fn intcode(input: &[i64], system_id: i64) -> Result<i64> {
+    let outputs = IntcodeVm::new(input).run_with_inputs(&[system_id])?;
+
+    if DEBUG_PRINT {
+        println!("{:?}", outputs);
+    }
+
+    outputs
+        .last()
+        .copied()
+        .ok_or_else(|| Error::solve("The program gave no output"))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_program(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(intcode(input, 1)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(intcode(input, 2)?.into())
+    }
+}
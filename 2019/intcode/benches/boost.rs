@@ -0,0 +1,127 @@
+//! Compares the VM's paged memory against the `BTreeMap<i64, i64>` memory the
+//! 2019 days used before the shared VM, and the interpreter against the
+//! pre-decoded backend, on day09's BOOST program in sensor boost mode.
+//! Without a `2019/day09/Input.txt` a stand-in loop with the same kind of
+//! relative-mode traffic is used instead.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use intcode::{assemble, parse_program, CompiledVm, IntcodeVm};
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+const STAND_IN: &str = "
+        ARB #1000               ; work far past the end of the program
+        IN [rb+4]
+        ADD #0, #0, [rb+0]
+outer:  ADD #0, #0, [rb+1]
+inner:  ADD [rb+1], #1, [rb+1]
+        MUL [rb+1], [rb+0], [rb+2]
+        LT [rb+1], #1000, [rb+3]
+        JNZ [rb+3], #inner
+        ADD [rb+0], #1, [rb+0]
+        LT [rb+0], #100, [rb+3]
+        JNZ [rb+3], #outer
+        OUT [rb+2]
+        HLT
+";
+
+fn boost_program() -> Vec<i64> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../day09/Input.txt");
+    match fs::read_to_string(&path) {
+        Ok(s) => parse_program(&s).expect("Couldn't parse BOOST program"),
+        Err(_) => {
+            eprintln!("No {}, using a stand-in program", path.display());
+            assemble(STAND_IN).unwrap()
+        }
+    }
+}
+
+fn address(memory: &BTreeMap<i64, i64>, pc: i64, rb: i64, index: i64) -> i64 {
+    let value = memory.get(&pc).copied().unwrap_or(0);
+    let raw = memory.get(&(pc + index)).copied().unwrap_or(0);
+    match value / 10_i64.pow(index as u32 + 1) % 10 {
+        0 => raw,
+        1 => pc + index,
+        2 => rb + raw,
+        mode => panic!("Unexpected mode {}", mode),
+    }
+}
+
+/// The pre-VM interpreter loop, reduced to its memory access pattern.
+fn run_btreemap(program: &[i64], input: i64) -> Vec<i64> {
+    let mut memory: BTreeMap<i64, i64> = (0..).zip(program.iter().copied()).collect();
+    let (mut pc, mut rb) = (0, 0);
+    let mut outputs = Vec::new();
+
+    loop {
+        let get = |memory: &BTreeMap<i64, i64>, index| {
+            let address = address(memory, pc, rb, index);
+            memory.get(&address).copied().unwrap_or(0)
+        };
+
+        match memory.get(&pc).copied().unwrap_or(0) % 100 {
+            opcode @ (1 | 2 | 7 | 8) => {
+                let (a, b) = (get(&memory, 1), get(&memory, 2));
+                let value = match opcode {
+                    1 => a + b,
+                    2 => a * b,
+                    7 => (a < b) as i64,
+                    _ => (a == b) as i64,
+                };
+                let target = address(&memory, pc, rb, 3);
+                memory.insert(target, value);
+                pc += 4;
+            }
+            3 => {
+                let target = address(&memory, pc, rb, 1);
+                memory.insert(target, input);
+                pc += 2;
+            }
+            4 => {
+                outputs.push(get(&memory, 1));
+                pc += 2;
+            }
+            opcode @ (5 | 6) => {
+                if (get(&memory, 1) != 0) == (opcode == 5) {
+                    pc = get(&memory, 2);
+                } else {
+                    pc += 3;
+                }
+            }
+            9 => {
+                rb += get(&memory, 1);
+                pc += 2;
+            }
+            99 => return outputs,
+            opcode => panic!("Unexpected opcode {}", opcode),
+        }
+    }
+}
+
+fn boost(c: &mut Criterion) {
+    let program = boost_program();
+    assert_eq!(
+        IntcodeVm::new(&program).run_with_inputs(&[2]),
+        Ok(run_btreemap(&program, 2))
+    );
+    assert_eq!(
+        CompiledVm::new(&program).run_with_inputs(&[2]),
+        Ok(run_btreemap(&program, 2))
+    );
+
+    let mut group = c.benchmark_group("boost");
+    group.sample_size(20);
+    group.bench_function("paged", |b| {
+        b.iter(|| IntcodeVm::new(&program).run_with_inputs(&[2]))
+    });
+    group.bench_function("compiled", |b| {
+        b.iter(|| CompiledVm::new(&program).run_with_inputs(&[2]))
+    });
+    group.bench_function("btreemap", |b| b.iter(|| run_btreemap(&program, 2)));
+    group.finish();
+}
+
+criterion_group!(benches, boost);
+criterion_main!(benches);
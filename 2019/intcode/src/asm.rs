@@ -0,0 +1,339 @@
+//! A small assembler, the inverse of [`crate::disasm`].
+//!
+//! One statement per line, `;` starts a comment:
+//!
+//! ```text
+//! start:  IN [rb+1]                 ; labels end with a colon
+//!         JZ [rb+1], #done          ; #x immediate, [x] position, [rb+x] relative
+//!         OUT [counter]
+//!         JNZ #1, #start
+//! done:   HLT
+//! counter: DATA 0, -1, start+2      ; raw values
+//! ```
+//!
+//! Operands are a number or a label, optionally with `+`/`-` offsets.
+
+use crate::instruction::{Instruction, Mode, Opcode};
+
+use aoc_common::{Error, Result};
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Term {
+    // Wide enough for the magnitude of i64::MIN, which only fits once the
+    // sign is applied.
+    Number(i128),
+    Label(String),
+}
+
+/// A sum of terms, each with its sign.
+type Expr = Vec<(i64, Term)>;
+
+#[derive(Debug)]
+enum Statement {
+    Instruction {
+        opcode: Opcode,
+        operands: Vec<(Mode, Expr)>,
+    },
+    Data(Vec<Expr>),
+}
+
+impl Statement {
+    fn size(&self) -> usize {
+        match self {
+            Statement::Instruction { opcode, .. } => 1 + opcode.parameter_count(),
+            Statement::Data(values) => values.len(),
+        }
+    }
+}
+
+fn is_label(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !s.eq_ignore_ascii_case("rb")
+}
+
+fn parse_term(s: &str) -> std::result::Result<Term, String> {
+    if is_label(s) {
+        Ok(Term::Label(s.to_string()))
+    } else {
+        s.parse()
+            .map(Term::Number)
+            .map_err(|_| format!("Invalid value {:?}", s))
+    }
+}
+
+fn parse_expr(s: &str) -> std::result::Result<Expr, String> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err("Missing value".to_string());
+    }
+
+    let mut expr = Expr::new();
+    let mut sign = 1;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        if c != '+' && c != '-' {
+            continue;
+        }
+        let term = s[start..i].trim();
+        if term.is_empty() {
+            // A leading sign, or several in a row.
+            if c == '-' {
+                sign = -sign;
+            }
+        } else {
+            expr.push((sign, parse_term(term)?));
+            sign = if c == '-' { -1 } else { 1 };
+        }
+        start = i + 1;
+    }
+
+    let term = s[start..].trim();
+    if term.is_empty() {
+        return Err(format!("Invalid value {:?}", s));
+    }
+    expr.push((sign, parse_term(term)?));
+
+    Ok(expr)
+}
+
+fn parse_operand(s: &str) -> std::result::Result<(Mode, Expr), String> {
+    let s = s.trim();
+    if let Some(value) = s.strip_prefix('#') {
+        return Ok((Mode::Immediate, parse_expr(value)?));
+    }
+
+    let Some(inner) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) else {
+        return Err(format!("Invalid operand {:?}", s));
+    };
+    let inner = inner.trim();
+
+    let base = inner.get(..2).filter(|b| b.eq_ignore_ascii_case("rb"));
+    let rest = inner.get(2..).unwrap_or("").trim_start();
+    if base.is_some() && (rest.is_empty() || rest.starts_with(['+', '-'])) {
+        if rest.is_empty() {
+            return Ok((Mode::Relative, vec![(1, Term::Number(0))]));
+        }
+        // Keep the sign: "+3" and "-3" are both valid expressions.
+        return Ok((Mode::Relative, parse_expr(rest)?));
+    }
+
+    Ok((Mode::Position, parse_expr(inner)?))
+}
+
+fn parse_statement(s: &str) -> std::result::Result<Statement, String> {
+    let (mnemonic, rest) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+    let arguments: Vec<&str> = if rest.trim().is_empty() {
+        Vec::new()
+    } else {
+        rest.split(',').collect()
+    };
+
+    if mnemonic.eq_ignore_ascii_case("DATA") {
+        let values = arguments
+            .iter()
+            .map(|a| parse_expr(a))
+            .collect::<std::result::Result<_, _>>()?;
+        return Ok(Statement::Data(values));
+    }
+
+    let opcode =
+        Opcode::from_mnemonic(mnemonic).ok_or_else(|| format!("Unknown mnemonic {}", mnemonic))?;
+    if arguments.len() != opcode.parameter_count() {
+        return Err(format!(
+            "{} takes {} operands, found {}",
+            opcode.mnemonic(),
+            opcode.parameter_count(),
+            arguments.len()
+        ));
+    }
+
+    let operands: Vec<(Mode, Expr)> = arguments
+        .iter()
+        .map(|a| parse_operand(a))
+        .collect::<std::result::Result<_, _>>()?;
+    if opcode.writes()
+        && operands
+            .last()
+            .is_some_and(|(mode, _)| *mode == Mode::Immediate)
+    {
+        return Err(format!(
+            "{} can't write to an immediate operand",
+            opcode.mnemonic()
+        ));
+    }
+
+    Ok(Statement::Instruction { opcode, operands })
+}
+
+fn evaluate(expr: &Expr, labels: &HashMap<String, i64>) -> std::result::Result<i64, String> {
+    let total = expr.iter().try_fold(0i128, |total, (sign, term)| {
+        let value = match term {
+            Term::Number(n) => *n,
+            Term::Label(name) => *labels
+                .get(name)
+                .ok_or_else(|| format!("Unknown label {}", name))?
+                as i128,
+        };
+        total
+            .checked_add(*sign as i128 * value)
+            .ok_or_else(|| "Value out of range".to_string())
+    })?;
+    i64::try_from(total).map_err(|_| format!("Value {} out of range", total))
+}
+
+/// Assembles source text into an Intcode program.
+pub fn assemble(source: &str) -> Result<Vec<i64>> {
+    let error = |line: usize, message: String| Error::parse(format!("line {}: {}", line, message));
+
+    let mut labels: HashMap<String, i64> = HashMap::new();
+    let mut statements: Vec<(usize, Statement)> = Vec::new();
+    let mut address = 0;
+
+    for (i, line) in source.split("\n").enumerate() {
+        let line_number = i + 1;
+        let mut line = line.split(';').next().unwrap().trim();
+
+        while let Some((label, rest)) = line.split_once(':') {
+            let label = label.trim();
+            if !is_label(label) {
+                return Err(error(line_number, format!("Invalid label {:?}", label)));
+            }
+            if labels.insert(label.to_string(), address as i64).is_some() {
+                return Err(error(line_number, format!("Duplicate label {}", label)));
+            }
+            line = rest.trim();
+        }
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let statement = parse_statement(line).map_err(|e| error(line_number, e))?;
+        address += statement.size();
+        statements.push((line_number, statement));
+    }
+
+    let mut program = Vec::with_capacity(address);
+    for (line_number, statement) in statements {
+        let evaluate = |expr: &Expr| evaluate(expr, &labels).map_err(|e| error(line_number, e));
+        match statement {
+            Statement::Instruction { opcode, operands } => {
+                let mut modes = [Mode::Position; 3];
+                for (mode, (operand_mode, _)) in modes.iter_mut().zip(&operands) {
+                    *mode = *operand_mode;
+                }
+                program.push(Instruction { opcode, modes }.encode());
+                for (_, expr) in &operands {
+                    program.push(evaluate(expr)?);
+                }
+            }
+            Statement::Data(values) => {
+                for expr in &values {
+                    program.push(evaluate(expr)?);
+                }
+            }
+        }
+    }
+
+    Ok(program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disasm::disassemble;
+
+    #[test]
+    fn encodes_modes() {
+        assert_eq!(assemble("ADD [rb+3], #5, [12]").unwrap(), [1201, 3, 5, 12]);
+        assert_eq!(
+            assemble("mul [rb-1], [rb], [rb+2]").unwrap(),
+            [22202, -1, 0, 2]
+        );
+        assert_eq!(assemble("OUT #-7\nHLT").unwrap(), [104, -7, 99]);
+    }
+
+    #[test]
+    fn resolves_labels() {
+        let source = "
+            start: IN [value]      ; read a number
+                   JZ [value], #end
+                   OUT [value]
+                   JNZ #1, #start
+            end:   HLT
+            value: DATA 0, value+1, end-start
+        ";
+        assert_eq!(
+            assemble(source).unwrap(),
+            [3, 11, 1006, 11, 10, 4, 11, 1105, 1, 0, 99, 0, 12, 10]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_source() {
+        for source in [
+            "NOP",
+            "ADD #1, #2",
+            "ADD #1, #2, #3",
+            "IN [missing]",
+            "a: HLT\na: HLT",
+            "OUT 5",
+            "OUT [rb*2]",
+        ] {
+            assert!(assemble(source).is_err(), "{:?} should fail", source);
+        }
+    }
+
+    #[test]
+    fn checks_value_range() {
+        assert_eq!(
+            assemble("DATA -9223372036854775808, 9223372036854775807").unwrap(),
+            [i64::MIN, i64::MAX]
+        );
+        assert_eq!(
+            assemble("OUT [rb-9223372036854775808]").unwrap(),
+            [204, i64::MIN]
+        );
+
+        let err = assemble("HLT\nDATA 9223372036854775807+1").unwrap_err();
+        assert!(err.to_string().contains("line 2"), "{}", err);
+        assert!(assemble("DATA -9223372036854775809").is_err());
+        assert!(assemble("HLT\na: DATA 9223372036854775807+a").is_err());
+    }
+
+    #[test]
+    fn round_trips_every_opcode_and_mode() {
+        let modes = [Mode::Position, Mode::Immediate, Mode::Relative];
+        for opcode in Opcode::ALL {
+            let count = opcode.parameter_count();
+            for combination in 0..3usize.pow(count as u32) {
+                let mut instruction_modes = [Mode::Position; 3];
+                let mut operands = Vec::new();
+                for (i, mode) in instruction_modes.iter_mut().take(count).enumerate() {
+                    *mode = modes[combination / 3usize.pow(i as u32) % 3];
+                    operands.push(crate::disasm::format_operand(*mode, i as i64 - 1));
+                }
+                if opcode.writes() && instruction_modes[count - 1] == Mode::Immediate {
+                    continue;
+                }
+
+                let source = format!("{} {}", opcode.mnemonic(), operands.join(", "));
+                let source = source.trim_end();
+                let program = assemble(source).unwrap();
+                let instruction = Instruction {
+                    opcode,
+                    modes: instruction_modes,
+                };
+                assert_eq!(program[0], instruction.encode(), "{}", source);
+                assert_eq!(Instruction::decode(program[0]), Some(instruction));
+                assert_eq!(disassemble(&program).lines[0].to_string(), source);
+            }
+        }
+    }
+}
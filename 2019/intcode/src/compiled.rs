@@ -0,0 +1,474 @@
+//! A faster backend for programs that leave their own code alone. The
+//! program is decoded up front into a table with one entry per cell: the
+//! instruction's operands with their modes resolved, and the handler that
+//! executes it, so a step is a table lookup and an indirect call.
+//!
+//! The table covers the program as loaded and never grows. Writes into
+//! cells no executed instruction was decoded from re-decode the entries
+//! they overlap, since they may be data or code that hasn't run yet. The
+//! first write into an instruction that has already run, or a jump past
+//! the table, drops the table and the rest of the run goes through the
+//! interpreter.
+
+use crate::error::IntcodeError;
+use crate::instruction::{Instruction, Mode, Opcode};
+use crate::vm::{IntcodeVm, VmState};
+
+/// At most this many cells are decoded. VMs handed over with more memory
+/// than this fall back to the interpreter once they run past it.
+const MAX_COMPILED: usize = 1 << 16;
+
+#[derive(Debug, Clone, Copy)]
+enum Operand {
+    Position(i64),
+    Immediate(i64),
+    Relative(i64),
+}
+
+/// Executes an instruction given its operands and the address of the next
+/// one.
+type Handler = fn(&mut CompiledVm, [Operand; 3], usize) -> Result<VmState, IntcodeError>;
+
+#[derive(Clone, Copy)]
+struct Op {
+    opcode: Opcode,
+    handler: Handler,
+    operands: [Operand; 3],
+    size: usize,
+    /// Set the first time the instruction runs.
+    ran: bool,
+}
+
+impl std::fmt::Debug for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Op")
+            .field("opcode", &self.opcode)
+            .field("operands", &self.operands)
+            .field("ran", &self.ran)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CompiledVm {
+    vm: IntcodeVm,
+    ops: Vec<Option<Op>>,
+    /// Cells some instruction that has already run was decoded from.
+    code: Vec<bool>,
+    compiled: bool,
+}
+
+impl CompiledVm {
+    pub fn new(program: &[i64]) -> CompiledVm {
+        CompiledVm::from_vm(IntcodeVm::new(program))
+    }
+
+    /// Takes over a VM, wherever it is in its program.
+    pub fn from_vm(vm: IntcodeVm) -> CompiledVm {
+        let len = vm.memory().len().min(MAX_COMPILED);
+        let mut compiled = CompiledVm {
+            vm,
+            ops: Vec::with_capacity(len),
+            code: vec![false; len],
+            compiled: true,
+        };
+        for pc in 0..len {
+            let op = compiled.decode(pc);
+            compiled.ops.push(op);
+        }
+        compiled
+    }
+
+    /// False once the program has written into its own code or run past
+    /// the decoded part of memory, and execution has fallen back to the
+    /// interpreter.
+    pub fn is_compiled(&self) -> bool {
+        self.compiled
+    }
+
+    pub fn vm(&self) -> &IntcodeVm {
+        &self.vm
+    }
+
+    pub fn into_vm(self) -> IntcodeVm {
+        self.vm
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.vm.push_input(value);
+    }
+
+    /// Decodes the instruction at `pc`. Anything the interpreter would
+    /// reject is left to the interpreter, so errors come out the same from
+    /// both backends, and so are instructions running off the table.
+    fn decode(&self, pc: usize) -> Option<Op> {
+        let instruction = Instruction::decode(self.vm.read(pc))?;
+        let count = instruction.opcode.parameter_count();
+        if instruction.opcode.writes() && instruction.modes[count - 1] == Mode::Immediate {
+            return None;
+        }
+        if pc + instruction.size() > self.code.len() {
+            return None;
+        }
+
+        let mut operands = [Operand::Immediate(0); 3];
+        for (i, operand) in operands.iter_mut().enumerate().take(count) {
+            let raw = self.vm.read(pc + 1 + i);
+            *operand = match instruction.modes[i] {
+                Mode::Position => Operand::Position(raw),
+                Mode::Immediate => Operand::Immediate(raw),
+                Mode::Relative => Operand::Relative(raw),
+            };
+        }
+
+        let handler: Handler = match instruction.opcode {
+            Opcode::Add => CompiledVm::add,
+            Opcode::Multiply => CompiledVm::multiply,
+            Opcode::LessThan => CompiledVm::less_than,
+            Opcode::Equals => CompiledVm::equals,
+            Opcode::Input => CompiledVm::input,
+            Opcode::Output => CompiledVm::output,
+            Opcode::JumpIfTrue => CompiledVm::jump_if_true,
+            Opcode::JumpIfFalse => CompiledVm::jump_if_false,
+            Opcode::AdjustRelativeBase => CompiledVm::adjust_relative_base,
+            Opcode::Halt => CompiledVm::halt,
+        };
+        Some(Op {
+            opcode: instruction.opcode,
+            handler,
+            operands,
+            size: instruction.size(),
+            ran: false,
+        })
+    }
+
+    fn fall_back(&mut self) {
+        self.compiled = false;
+        self.ops = Vec::new();
+        self.code = Vec::new();
+    }
+
+    fn value(&self, operand: Operand) -> Result<i64, IntcodeError> {
+        match operand {
+            Operand::Position(raw) => self.vm.load(raw),
+            Operand::Immediate(value) => Ok(value),
+            Operand::Relative(raw) => self
+                .vm
+                .load(self.vm.checked(self.vm.relative_base.checked_add(raw))?),
+        }
+    }
+
+    fn store(&mut self, operand: Operand, value: i64) -> Result<(), IntcodeError> {
+        let address = match operand {
+            Operand::Position(raw) => raw,
+            Operand::Relative(raw) => self.vm.checked(self.vm.relative_base.checked_add(raw))?,
+            Operand::Immediate(_) => unreachable!("decode rejects immediate writes"),
+        };
+        self.vm.store(address, value)?;
+
+        let address = address as usize;
+        if address >= self.code.len() {
+            return Ok(());
+        }
+        if self.code[address] {
+            self.fall_back();
+            return Ok(());
+        }
+
+        // Entries starting at the cell, or whose operands cover it. An
+        // instruction is at most four cells long.
+        for pc in address.saturating_sub(3)..=address {
+            let overlaps = pc == address || self.ops[pc].is_some_and(|op| pc + op.size > address);
+            if overlaps {
+                self.ops[pc] = self.decode(pc);
+            }
+        }
+        Ok(())
+    }
+
+    fn add(&mut self, [a, b, c]: [Operand; 3], next: usize) -> Result<VmState, IntcodeError> {
+        let value = self
+            .vm
+            .checked(self.value(a)?.checked_add(self.value(b)?))?;
+        self.store(c, value)?;
+        self.vm.pc = next;
+        Ok(VmState::Running)
+    }
+
+    fn multiply(&mut self, [a, b, c]: [Operand; 3], next: usize) -> Result<VmState, IntcodeError> {
+        let value = self
+            .vm
+            .checked(self.value(a)?.checked_mul(self.value(b)?))?;
+        self.store(c, value)?;
+        self.vm.pc = next;
+        Ok(VmState::Running)
+    }
+
+    fn less_than(&mut self, [a, b, c]: [Operand; 3], next: usize) -> Result<VmState, IntcodeError> {
+        let value = (self.value(a)? < self.value(b)?) as i64;
+        self.store(c, value)?;
+        self.vm.pc = next;
+        Ok(VmState::Running)
+    }
+
+    fn equals(&mut self, [a, b, c]: [Operand; 3], next: usize) -> Result<VmState, IntcodeError> {
+        let value = (self.value(a)? == self.value(b)?) as i64;
+        self.store(c, value)?;
+        self.vm.pc = next;
+        Ok(VmState::Running)
+    }
+
+    fn input(&mut self, [a, _, _]: [Operand; 3], next: usize) -> Result<VmState, IntcodeError> {
+        let Some(&value) = self.vm.inputs.front() else {
+            return Ok(VmState::NeedsInput);
+        };
+        self.store(a, value)?;
+        self.vm.inputs.pop_front();
+        self.vm.pc = next;
+        Ok(VmState::Running)
+    }
+
+    fn output(&mut self, [a, _, _]: [Operand; 3], next: usize) -> Result<VmState, IntcodeError> {
+        let value = self.value(a)?;
+        self.vm.pc = next;
+        Ok(VmState::Output(value))
+    }
+
+    fn jump_if_true(
+        &mut self,
+        [a, b, _]: [Operand; 3],
+        next: usize,
+    ) -> Result<VmState, IntcodeError> {
+        if self.value(a)? != 0 {
+            let target = self.value(b)?;
+            self.vm.jump(target)?;
+        } else {
+            self.vm.pc = next;
+        }
+        Ok(VmState::Running)
+    }
+
+    fn jump_if_false(
+        &mut self,
+        [a, b, _]: [Operand; 3],
+        next: usize,
+    ) -> Result<VmState, IntcodeError> {
+        if self.value(a)? == 0 {
+            let target = self.value(b)?;
+            self.vm.jump(target)?;
+        } else {
+            self.vm.pc = next;
+        }
+        Ok(VmState::Running)
+    }
+
+    fn adjust_relative_base(
+        &mut self,
+        [a, _, _]: [Operand; 3],
+        next: usize,
+    ) -> Result<VmState, IntcodeError> {
+        let offset = self.value(a)?;
+        self.vm.relative_base = self.vm.checked(self.vm.relative_base.checked_add(offset))?;
+        self.vm.pc = next;
+        Ok(VmState::Running)
+    }
+
+    fn halt(&mut self, _: [Operand; 3], _: usize) -> Result<VmState, IntcodeError> {
+        Ok(VmState::Halted)
+    }
+
+    /// Executes a single instruction, with the same results as
+    /// `IntcodeVm::step`.
+    pub fn step(&mut self) -> Result<VmState, IntcodeError> {
+        if !self.compiled {
+            return self.vm.step();
+        }
+
+        let pc = self.vm.pc;
+        if pc >= self.ops.len() {
+            self.fall_back();
+            return self.vm.step();
+        }
+        let Some(op) = self.ops[pc].as_mut() else {
+            // Only instructions the interpreter rejects, or that run off
+            // the table, aren't decoded.
+            let runs_off = self
+                .vm
+                .current_instruction()
+                .is_ok_and(|instruction| pc + instruction.size() > self.ops.len());
+            if runs_off {
+                self.fall_back();
+            }
+            return self.vm.step();
+        };
+        if !op.ran {
+            op.ran = true;
+            self.code[pc..pc + op.size].fill(true);
+        }
+        let op = *op;
+
+        if let Some(limit) = self.vm.step_limit {
+            if self.vm.steps >= limit && op.opcode != Opcode::Halt {
+                return Err(IntcodeError::StepLimitExceeded { pc, limit });
+            }
+        }
+
+        let state = (op.handler)(self, op.operands, pc + op.size)?;
+        if matches!(state, VmState::Running | VmState::Output(_)) {
+            self.vm.steps += 1;
+        }
+        Ok(state)
+    }
+
+    /// Runs until the program produces an output, needs input it doesn't
+    /// have, or halts.
+    pub fn run(&mut self) -> Result<VmState, IntcodeError> {
+        loop {
+            let state = self.step()?;
+            if state != VmState::Running {
+                return Ok(state);
+            }
+        }
+    }
+
+    /// Runs to completion feeding `inputs` in order and returns everything
+    /// the program printed.
+    pub fn run_with_inputs(&mut self, inputs: &[i64]) -> Result<Vec<i64>, IntcodeError> {
+        self.vm.inputs.extend(inputs);
+
+        let mut outputs = Vec::new();
+        loop {
+            match self.run()? {
+                VmState::Output(value) => outputs.push(value),
+                VmState::Halted => return Ok(outputs),
+                _ => return Err(IntcodeError::InputExhausted { pc: self.vm.pc }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    /// Runs both backends in lockstep and checks every step leaves them in
+    /// the same state. Returns whether the compiled one stayed compiled.
+    fn cross_check(program: &[i64], inputs: &[i64]) -> bool {
+        let mut vm = IntcodeVm::new(program);
+        let mut compiled = CompiledVm::new(program);
+        vm.set_step_limit(100_000);
+        compiled.vm.set_step_limit(100_000);
+        for &value in inputs {
+            vm.push_input(value);
+            compiled.push_input(value);
+        }
+
+        loop {
+            let expected = vm.step();
+            assert_eq!(compiled.step(), expected, "at step {}", vm.steps());
+            assert_eq!(compiled.vm().pc(), vm.pc());
+            assert_eq!(compiled.vm().relative_base(), vm.relative_base());
+            assert_eq!(compiled.vm().steps(), vm.steps());
+            if !matches!(expected, Ok(VmState::Running | VmState::Output(_))) {
+                break;
+            }
+        }
+        assert_eq!(compiled.vm().memory().to_vec(), vm.memory().to_vec());
+        compiled.is_compiled()
+    }
+
+    #[test]
+    fn matches_the_interpreter() {
+        let compare_to_8 = crate::parse_program(
+            "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,\
+             20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99",
+        )
+        .unwrap();
+        for input in [7, 8, 9] {
+            assert!(cross_check(&compare_to_8, &[input]));
+        }
+
+        let quine = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        assert!(cross_check(&quine, &[]));
+        assert_eq!(
+            CompiledVm::new(&quine).run_with_inputs(&[]),
+            Ok(quine.to_vec())
+        );
+
+        // Errors, running out of input and the step limit.
+        cross_check(&[1, 0, 0, 0, 42], &[]);
+        cross_check(&[3, 9, 3, 10, 99], &[5]);
+        cross_check(&[1105, 1, 0], &[]);
+        cross_check(&[2, -1, 0, 0, 99], &[]);
+    }
+
+    #[test]
+    fn falls_back_on_self_modifying_code() {
+        // Day 2's example writes over its first instruction's operands.
+        assert!(!cross_check(
+            &[1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50],
+            &[]
+        ));
+
+        // Patches the ADD at `patch` into a MUL the second time through.
+        let program = assemble(
+            "
+            loop:   ADD [n], #1, [n]
+            patch:  ADD [x], [x], [x]
+                    OUT [x]
+                    ADD #2, #0, [patch]
+                    LT [n], #3, [t]
+                    JNZ [t], #loop
+                    HLT
+            n:      DATA 0
+            x:      DATA 3
+            t:      DATA 0
+            ",
+        )
+        .unwrap();
+        assert!(!cross_check(&program, &[]));
+        assert_eq!(
+            CompiledVm::new(&program).run_with_inputs(&[]),
+            Ok(vec![6, 36, 1296])
+        );
+    }
+
+    #[test]
+    fn redecodes_code_that_hasnt_run() {
+        // Turns the OUT #7 at `later` into OUT #9 before reaching it, and
+        // writes to data and far past the program on the way.
+        let program = assemble(
+            "
+                    ADD #9, #0, [later+1]
+                    ADD #1, #2, [x]
+                    ADD #1, #2, [5000]
+            later:  OUT #7
+                    HLT
+            x:      DATA 0
+            ",
+        )
+        .unwrap();
+        assert!(cross_check(&program, &[]));
+
+        let mut compiled = CompiledVm::new(&program);
+        assert_eq!(compiled.run_with_inputs(&[]), Ok(vec![9]));
+        assert!(compiled.is_compiled());
+        assert_eq!(compiled.ops.len(), program.len());
+    }
+
+    #[test]
+    fn falls_back_past_the_table() {
+        // Jumps to an instruction written past the end of the program.
+        let program = assemble(
+            "
+                    ADD #99, #0, [20]
+                    JNZ #1, #20
+            ",
+        )
+        .unwrap();
+        assert!(!cross_check(&program, &[]));
+    }
+}
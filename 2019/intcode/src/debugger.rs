@@ -0,0 +1,444 @@
+//! A line-oriented debugger. Commands come from any reader, so it works just
+//! as well with a script piped into stdin as it does interactively.
+
+use crate::disasm::{line_at, Line};
+use crate::error::IntcodeError;
+use crate::vm::{IntcodeVm, VmState};
+
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, Write};
+
+const HELP: &str = "\
+step [N]          s   execute N instructions (default 1)
+continue          c   run until a breakpoint, watchpoint, input wait, error or halt
+break [ADDR]      b   set a breakpoint, or list them
+delete ADDR       d   remove a breakpoint
+watch [ADDR]      w   stop when a memory cell changes, or list watchpoints
+unwatch ADDR          remove a watchpoint
+print ADDR [N]    p   show N memory cells starting at ADDR
+set ADDR VALUE        write VALUE to memory
+input VALUE...    i   queue values for the program's input instructions
+info                  show pc, relative base, state and pending I/O
+list [ADDR] [N]   l   disassemble N instructions (default: from pc)
+help              h
+quit              q
+
+ADDR is a number, `[12]`, or relative to the base like `[rb+3]`.";
+
+/// Why execution stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    Stepped,
+    Breakpoint(usize),
+    Watchpoint { address: usize, old: i64, new: i64 },
+    NeedsInput,
+    Halted,
+    Error(IntcodeError),
+}
+
+enum CommandError {
+    Usage(String),
+    Io(io::Error),
+}
+
+impl From<String> for CommandError {
+    fn from(message: String) -> CommandError {
+        CommandError::Usage(message)
+    }
+}
+
+impl From<&str> for CommandError {
+    fn from(message: &str) -> CommandError {
+        CommandError::Usage(message.to_string())
+    }
+}
+
+impl From<io::Error> for CommandError {
+    fn from(e: io::Error) -> CommandError {
+        CommandError::Io(e)
+    }
+}
+
+fn parse_number(s: &str) -> Result<i64, String> {
+    s.parse().map_err(|_| format!("Invalid number {}", s))
+}
+
+fn parse_count(arg: Option<&&str>, default: usize) -> Result<usize, String> {
+    match arg {
+        Some(s) => s.parse().map_err(|_| format!("Invalid count {}", s)),
+        None => Ok(default),
+    }
+}
+
+/// Accepts `12`, `[12]`, `[rb]` or `[rb+3]`/`[rb-3]`.
+fn parse_address(s: &str, relative_base: i64) -> Result<usize, String> {
+    let invalid = || format!("Invalid address {}", s);
+    let inner = s
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(s)
+        .trim();
+
+    let address = match inner.strip_prefix("rb") {
+        Some(offset) if offset.trim().is_empty() => relative_base,
+        Some(offset) => {
+            let offset = offset.trim();
+            let offset = offset.strip_prefix('+').unwrap_or(offset).trim();
+            let offset = offset.parse::<i64>().map_err(|_| invalid())?;
+            relative_base.checked_add(offset).ok_or_else(invalid)?
+        }
+        None => inner.parse().map_err(|_| invalid())?,
+    };
+
+    usize::try_from(address).map_err(|_| format!("Negative address {}", s))
+}
+
+pub struct Debugger {
+    vm: IntcodeVm,
+    breakpoints: BTreeSet<usize>,
+    watchpoints: BTreeSet<usize>,
+    outputs: Vec<i64>,
+    state: VmState,
+}
+
+impl Debugger {
+    pub fn new(program: &[i64]) -> Debugger {
+        Debugger {
+            vm: IntcodeVm::new(program),
+            breakpoints: BTreeSet::new(),
+            watchpoints: BTreeSet::new(),
+            outputs: Vec::new(),
+            state: VmState::Running,
+        }
+    }
+
+    pub fn vm(&self) -> &IntcodeVm {
+        &self.vm
+    }
+
+    pub fn outputs(&self) -> &[i64] {
+        &self.outputs
+    }
+
+    fn current_line(&self) -> Line {
+        line_at(|a| self.vm.read(a), self.vm.pc())
+    }
+
+    /// Executes one instruction, returning why it had to stop if it did.
+    /// Outputs are collected and echoed to `out` as they happen.
+    fn step_once(&mut self, out: &mut impl Write) -> io::Result<Option<Stop>> {
+        if self.state == VmState::Halted {
+            return Ok(Some(Stop::Halted));
+        }
+        let watched: Vec<(usize, i64)> = self
+            .watchpoints
+            .iter()
+            .map(|&a| (a, self.vm.read(a)))
+            .collect();
+
+        self.state = match self.vm.step() {
+            Ok(state) => state,
+            Err(e) => return Ok(Some(Stop::Error(e))),
+        };
+        match self.state {
+            VmState::NeedsInput => return Ok(Some(Stop::NeedsInput)),
+            VmState::Halted => return Ok(Some(Stop::Halted)),
+            VmState::Output(value) => {
+                self.outputs.push(value);
+                writeln!(out, "output: {}", value)?;
+            }
+            VmState::Running => {}
+        }
+
+        for (address, old) in watched {
+            let new = self.vm.read(address);
+            if new != old {
+                return Ok(Some(Stop::Watchpoint { address, old, new }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn step(&mut self, count: usize, out: &mut impl Write) -> io::Result<Stop> {
+        for _ in 0..count {
+            if let Some(stop) = self.step_once(out)? {
+                return Ok(stop);
+            }
+        }
+
+        Ok(Stop::Stepped)
+    }
+
+    /// Runs until something stops it. A breakpoint on the current pc doesn't
+    /// count, so `continue` always makes progress.
+    pub fn resume(&mut self, out: &mut impl Write) -> io::Result<Stop> {
+        loop {
+            if let Some(stop) = self.step_once(out)? {
+                return Ok(stop);
+            }
+            if self.breakpoints.contains(&self.vm.pc()) {
+                return Ok(Stop::Breakpoint(self.vm.pc()));
+            }
+        }
+    }
+
+    fn report(&self, stop: Stop, out: &mut impl Write) -> io::Result<()> {
+        match stop {
+            Stop::Stepped => {}
+            Stop::Breakpoint(address) => writeln!(out, "breakpoint at {}", address)?,
+            Stop::Watchpoint { address, old, new } => {
+                writeln!(out, "watch [{}]: {} -> {}", address, old, new)?
+            }
+            Stop::NeedsInput => writeln!(out, "waiting for input")?,
+            Stop::Halted => writeln!(out, "halted")?,
+            Stop::Error(e) => writeln!(out, "{}", e)?,
+        }
+
+        self.show_line(&self.current_line(), out)
+    }
+
+    fn show_line(&self, line: &Line, out: &mut impl Write) -> io::Result<()> {
+        let marker = if line.address() == self.vm.pc() {
+            "=>"
+        } else if self.breakpoints.contains(&line.address()) {
+            " *"
+        } else {
+            "  "
+        };
+        writeln!(out, "{} {:>5}: {}", marker, line.address(), line)
+    }
+
+    fn info(&self, out: &mut impl Write) -> io::Result<()> {
+        let join = |values: Vec<String>| values.join(", ");
+
+        writeln!(out, "pc: {}", self.vm.pc())?;
+        writeln!(out, "relative_base: {}", self.vm.relative_base())?;
+        writeln!(out, "state: {:?}", self.state)?;
+        writeln!(
+            out,
+            "pending inputs: [{}]",
+            join(
+                self.vm
+                    .pending_inputs()
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect()
+            )
+        )?;
+        writeln!(
+            out,
+            "outputs: [{}]",
+            join(self.outputs.iter().map(|v| v.to_string()).collect())
+        )
+    }
+
+    fn list(&self, start: usize, count: usize, out: &mut impl Write) -> io::Result<()> {
+        let mut address = start;
+        for _ in 0..count {
+            let line = line_at(|a| self.vm.read(a), address);
+            self.show_line(&line, out)?;
+            address += line.size();
+        }
+
+        Ok(())
+    }
+
+    fn address(&self, args: &[&str]) -> Result<usize, CommandError> {
+        let arg = args.first().ok_or("Missing address")?;
+        Ok(parse_address(arg, self.vm.relative_base())?)
+    }
+
+    fn execute(
+        &mut self,
+        command: &str,
+        args: &[&str],
+        out: &mut impl Write,
+    ) -> Result<(), CommandError> {
+        match command {
+            "step" | "s" => {
+                let stop = self.step(parse_count(args.first(), 1)?, out)?;
+                self.report(stop, out)?;
+            }
+            "continue" | "c" => {
+                let stop = self.resume(out)?;
+                self.report(stop, out)?;
+            }
+            "break" | "b" if args.is_empty() => {
+                let list: Vec<String> = self.breakpoints.iter().map(|a| a.to_string()).collect();
+                writeln!(out, "breakpoints: {}", list.join(", "))?;
+            }
+            "break" | "b" => {
+                let address = self.address(args)?;
+                self.breakpoints.insert(address);
+            }
+            "delete" | "d" => {
+                let address = self.address(args)?;
+                self.breakpoints.remove(&address);
+            }
+            "watch" | "w" if args.is_empty() => {
+                let list: Vec<String> = self
+                    .watchpoints
+                    .iter()
+                    .map(|&a| format!("[{}] = {}", a, self.vm.read(a)))
+                    .collect();
+                writeln!(out, "watchpoints: {}", list.join(", "))?;
+            }
+            "watch" | "w" => {
+                let address = self.address(args)?;
+                self.watchpoints.insert(address);
+            }
+            "unwatch" => {
+                let address = self.address(args)?;
+                self.watchpoints.remove(&address);
+            }
+            "print" | "p" => {
+                let start = self.address(args)?;
+                let end = start
+                    .checked_add(parse_count(args.get(1), 1)?)
+                    .ok_or("Count runs past the end of memory")?;
+                for address in start..end {
+                    writeln!(out, "[{}] = {}", address, self.vm.read(address))?;
+                }
+            }
+            "set" => {
+                let address = self.address(args)?;
+                let value = parse_number(args.get(1).ok_or("Missing value")?)?;
+                self.vm.write(address, value).map_err(|e| e.to_string())?;
+            }
+            "input" | "i" => {
+                let values: Vec<i64> = args
+                    .iter()
+                    .flat_map(|a| a.split(','))
+                    .filter(|a| !a.is_empty())
+                    .map(parse_number)
+                    .collect::<Result<_, _>>()?;
+                for value in values {
+                    self.vm.push_input(value);
+                }
+                if self.state == VmState::NeedsInput {
+                    self.state = VmState::Running;
+                }
+            }
+            "info" => self.info(out)?,
+            "list" | "l" => {
+                let start = match args.first() {
+                    Some(_) => self.address(args)?,
+                    None => self.vm.pc(),
+                };
+                self.list(start, parse_count(args.get(1), 5)?, out)?;
+            }
+            "help" | "h" => writeln!(out, "{}", HELP)?,
+            _ => return Err(format!("Unknown command {}, try help", command).into()),
+        }
+
+        Ok(())
+    }
+
+    /// Handles one command line. Returns `false` once the user quits.
+    fn command(&mut self, line: &str, out: &mut impl Write) -> io::Result<bool> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, args)) = words.split_first() else {
+            return Ok(true);
+        };
+        if let "quit" | "q" = command {
+            return Ok(false);
+        }
+
+        match self.execute(command, args, out) {
+            Ok(()) => {}
+            Err(CommandError::Usage(message)) => writeln!(out, "{}", message)?,
+            Err(CommandError::Io(e)) => return Err(e),
+        }
+
+        Ok(true)
+    }
+
+    /// Reads commands from `input` until it's exhausted or the user quits.
+    pub fn repl(&mut self, input: impl BufRead, mut out: impl Write) -> io::Result<()> {
+        self.show_line(&self.current_line(), &mut out)?;
+
+        let mut lines = input.lines();
+        loop {
+            write!(out, "(intcode) ")?;
+            out.flush()?;
+
+            let Some(line) = lines.next() else {
+                writeln!(out)?;
+                return Ok(());
+            };
+            if !self.command(&line?, &mut out)? {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(program: &[i64], script: &str) -> (Debugger, String) {
+        let mut debugger = Debugger::new(program);
+        let mut out = Vec::new();
+        debugger.repl(script.as_bytes(), &mut out).unwrap();
+        (debugger, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn breakpoints_and_inputs() {
+        // IN [9], MUL [9], #2, [9], OUT [9], HLT, DATA 0
+        let program = [3, 9, 1002, 9, 2, 9, 4, 9, 99, 0];
+        let (debugger, out) = session(&program, "break 6\nc\ninput 21\nc\nc\nc\n");
+
+        assert!(out.contains("waiting for input"));
+        assert!(out.contains("breakpoint at 6"));
+        assert!(out.contains("output: 42"));
+        assert!(out.contains("halted"));
+        assert_eq!(debugger.outputs(), [42]);
+    }
+
+    #[test]
+    fn watchpoints_and_memory() {
+        let program = [1101, 2, 3, 7, 1101, 0, 0, 0, 99];
+        let (debugger, out) = session(&program, "watch [7]\nc\nset 8 5\nprint 7 2\nquit\nstep\n");
+
+        assert!(out.contains("watch [7]: 0 -> 5"));
+        assert!(out.contains("[7] = 5\n[8] = 5"));
+        assert_eq!(debugger.vm().pc(), 4);
+    }
+
+    #[test]
+    fn relative_addresses() {
+        let program = [109, 5, 21101, 1, 1, 1, 99];
+        let (_, out) = session(&program, "step 2\ninfo\nprint [rb+1]\n");
+
+        assert!(out.contains("relative_base: 5"));
+        assert!(out.contains("[6] = 2"));
+    }
+
+    #[test]
+    fn rejects_overflowing_addresses() {
+        let program = [109, 5, 99];
+        let script = format!(
+            "step
+print [rb+{}]
+print 5 {}
+",
+            i64::MAX,
+            usize::MAX
+        );
+        let (_, out) = session(&program, &script);
+
+        assert!(out.contains(&format!("Invalid address [rb+{}]", i64::MAX)));
+        assert!(out.contains("Count runs past the end of memory"));
+    }
+
+    #[test]
+    fn rejects_writes_past_memory() {
+        let (debugger, out) = session(&[99], "set 9000000000000000000 1\nset 3 7\n");
+
+        assert!(out.contains("Address 9000000000000000000 out of range"));
+        assert_eq!(debugger.vm().read(3), 7);
+    }
+}
@@ -0,0 +1,273 @@
+use crate::instruction::{Instruction, Mode, Opcode};
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Formats a raw parameter the way the assembler reads it back: `#5` for
+/// immediate, `[12]` for position and `[rb+3]` for relative.
+pub fn format_operand(mode: Mode, raw: i64) -> String {
+    match mode {
+        Mode::Position => format!("[{}]", raw),
+        Mode::Immediate => format!("#{}", raw),
+        Mode::Relative if raw < 0 => format!("[rb-{}]", raw.unsigned_abs()),
+        Mode::Relative => format!("[rb+{}]", raw),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Instruction {
+        address: usize,
+        instruction: Instruction,
+        parameters: Vec<i64>,
+    },
+    Data {
+        address: usize,
+        values: Vec<i64>,
+    },
+}
+
+impl Line {
+    pub fn address(&self) -> usize {
+        match self {
+            Line::Instruction { address, .. } | Line::Data { address, .. } => *address,
+        }
+    }
+
+    /// Number of memory cells the line covers.
+    pub fn size(&self) -> usize {
+        match self {
+            Line::Instruction { parameters, .. } => 1 + parameters.len(),
+            Line::Data { values, .. } => values.len(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Disassembly {
+    pub lines: Vec<Line>,
+    /// Addresses that are jumped to with an immediate target.
+    pub jump_targets: BTreeSet<usize>,
+    /// Reachable addresses that don't hold a valid instruction, with the value found there.
+    pub invalid: Vec<(usize, i64)>,
+}
+
+/// Decodes `value`, rejecting instructions that would write to an immediate operand.
+pub fn decode_valid(value: i64) -> Option<Instruction> {
+    let instruction = Instruction::decode(value)?;
+    let count = instruction.opcode.parameter_count();
+    if instruction.opcode.writes() && instruction.modes[count - 1] == Mode::Immediate {
+        return None;
+    }
+
+    Some(instruction)
+}
+
+fn decode_at(program: &[i64], address: usize) -> Option<Instruction> {
+    let instruction = decode_valid(program[address])?;
+    if address + instruction.size() > program.len() {
+        return None;
+    }
+
+    Some(instruction)
+}
+
+/// The single line at `address`, with `read` supplying memory. Anything
+/// that isn't a valid instruction comes back as data.
+pub fn line_at(read: impl Fn(usize) -> i64, address: usize) -> Line {
+    match decode_valid(read(address)) {
+        Some(instruction) => Line::Instruction {
+            address,
+            instruction,
+            parameters: (address + 1..address + instruction.size())
+                .map(read)
+                .collect(),
+        },
+        None => Line::Data {
+            address,
+            values: vec![read(address)],
+        },
+    }
+}
+
+/// Walks the program from address 0 following every branch it can resolve
+/// statically. Anything never reached is treated as data.
+pub fn disassemble(program: &[i64]) -> Disassembly {
+    let mut disassembly = Disassembly::default();
+    let mut starts: BTreeSet<usize> = BTreeSet::new();
+    let mut invalid: BTreeSet<usize> = BTreeSet::new();
+
+    let mut to_visit = vec![0];
+    while let Some(address) = to_visit.pop() {
+        if address >= program.len() || starts.contains(&address) || invalid.contains(&address) {
+            continue;
+        }
+
+        let Some(instruction) = decode_at(program, address) else {
+            invalid.insert(address);
+            continue;
+        };
+        starts.insert(address);
+
+        let mut falls_through = instruction.opcode != Opcode::Halt;
+        if let Opcode::JumpIfTrue | Opcode::JumpIfFalse = instruction.opcode {
+            let condition = program[address + 1];
+            let target = program[address + 2];
+            let jumps_on = instruction.opcode == Opcode::JumpIfTrue;
+
+            let always = instruction.modes[0] == Mode::Immediate && (condition != 0) == jumps_on;
+            let never = instruction.modes[0] == Mode::Immediate && (condition != 0) != jumps_on;
+
+            if instruction.modes[1] == Mode::Immediate && !never {
+                if let Ok(target) = usize::try_from(target) {
+                    disassembly.jump_targets.insert(target);
+                    to_visit.push(target);
+                }
+            }
+            falls_through = !always;
+        }
+
+        if falls_through {
+            to_visit.push(address + instruction.size());
+        }
+    }
+
+    let mut address = 0;
+    while address < program.len() {
+        if starts.contains(&address) {
+            let instruction = decode_at(program, address).unwrap();
+            let end = address + instruction.size();
+            disassembly.lines.push(Line::Instruction {
+                address,
+                instruction,
+                parameters: program[address + 1..end].to_vec(),
+            });
+            address = end;
+        } else {
+            let end = (address + 1..program.len())
+                .find(|a| starts.contains(a) || disassembly.jump_targets.contains(a))
+                .unwrap_or(program.len());
+            for chunk_start in (address..end).step_by(8) {
+                let chunk_end = (chunk_start + 8).min(end);
+                disassembly.lines.push(Line::Data {
+                    address: chunk_start,
+                    values: program[chunk_start..chunk_end].to_vec(),
+                });
+            }
+            address = end;
+        }
+    }
+
+    disassembly.invalid = invalid.into_iter().map(|a| (a, program[a])).collect();
+    disassembly
+}
+
+pub fn label(address: usize) -> String {
+    format!("L{}", address)
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Line::Instruction {
+                instruction,
+                parameters,
+                ..
+            } => {
+                write!(f, "{}", instruction.opcode.mnemonic())?;
+                for (i, (mode, raw)) in instruction.modes.iter().zip(parameters).enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(f, "{}{}", separator, format_operand(*mode, *raw))?;
+                }
+                Ok(())
+            }
+            Line::Data { values, .. } => {
+                let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                write!(f, "DATA {}", values.join(", "))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Disassembly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let invalid: BTreeSet<usize> = self.invalid.iter().map(|(a, _)| *a).collect();
+
+        for line in &self.lines {
+            let (address, raw) = match line {
+                Line::Instruction {
+                    address,
+                    instruction,
+                    parameters,
+                } => {
+                    let mut raw = vec![instruction.encode()];
+                    raw.extend(parameters);
+                    (*address, raw)
+                }
+                Line::Data { address, values } => (*address, values.clone()),
+            };
+
+            if self.jump_targets.contains(&address) {
+                writeln!(f, "{}:", label(address))?;
+            }
+
+            let raw: Vec<String> = raw.iter().map(|v| v.to_string()).collect();
+            write!(
+                f,
+                "    {:<32} ; {:>5}: {}",
+                line.to_string(),
+                address,
+                raw.join(",")
+            )?;
+            if invalid.contains(&address) {
+                write!(f, " (invalid opcode)")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_every_mode() {
+        let disassembly = disassemble(&[21101, 3, 5, 12, 99]);
+        let text: Vec<String> = disassembly.lines.iter().map(|l| l.to_string()).collect();
+        assert_eq!(text, ["ADD #3, #5, [rb+12]", "HLT"]);
+    }
+
+    #[test]
+    fn data_after_halt_and_jump_targets() {
+        // JNZ #1, #4 always jumps, so address 3 is never executed.
+        let program = [1105, 1, 4, 77, 99];
+        let disassembly = disassemble(&program);
+        assert_eq!(
+            disassembly.lines[1],
+            Line::Data {
+                address: 3,
+                values: vec![77]
+            }
+        );
+        assert!(disassembly.jump_targets.contains(&4));
+        assert!(disassembly.invalid.is_empty());
+    }
+
+    #[test]
+    fn reports_invalid_opcodes() {
+        let disassembly = disassemble(&[1, 0, 0, 0, 42, 99]);
+        assert_eq!(disassembly.invalid, [(4, 42)]);
+    }
+
+    #[test]
+    fn formats_extreme_offsets() {
+        let disassembly = disassemble(&[204, i64::MIN, 99]);
+        assert_eq!(
+            disassembly.lines[0].to_string(),
+            "OUT [rb-9223372036854775808]"
+        );
+    }
+}
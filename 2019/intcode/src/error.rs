@@ -0,0 +1,67 @@
+use std::fmt;
+
+use aoc_common::Error;
+
+/// Everything that can go wrong running a program. Each carries the pc of
+/// the instruction that failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntcodeError {
+    InvalidOpcode { pc: usize, value: i64 },
+    InvalidMode { pc: usize, value: i64 },
+    WriteInImmediateMode { pc: usize },
+    NegativeAddress { pc: usize, address: i64 },
+    AddressTooLarge { pc: usize, address: i64 },
+    Overflow { pc: usize },
+    InputExhausted { pc: usize },
+    StepLimitExceeded { pc: usize, limit: u64 },
+}
+
+impl IntcodeError {
+    pub fn pc(&self) -> usize {
+        match *self {
+            IntcodeError::InvalidOpcode { pc, .. }
+            | IntcodeError::InvalidMode { pc, .. }
+            | IntcodeError::WriteInImmediateMode { pc }
+            | IntcodeError::NegativeAddress { pc, .. }
+            | IntcodeError::AddressTooLarge { pc, .. }
+            | IntcodeError::Overflow { pc }
+            | IntcodeError::InputExhausted { pc }
+            | IntcodeError::StepLimitExceeded { pc, .. } => pc,
+        }
+    }
+}
+
+impl fmt::Display for IntcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntcodeError::InvalidOpcode { pc, value } => {
+                write!(f, "Invalid opcode in {} at {}", value, pc)
+            }
+            IntcodeError::InvalidMode { pc, value } => {
+                write!(f, "Invalid parameter mode in {} at {}", value, pc)
+            }
+            IntcodeError::WriteInImmediateMode { pc } => {
+                write!(f, "Write in immediate mode at {}", pc)
+            }
+            IntcodeError::NegativeAddress { pc, address } => {
+                write!(f, "Negative address {} at {}", address, pc)
+            }
+            IntcodeError::AddressTooLarge { pc, address } => {
+                write!(f, "Address {} out of range at {}", address, pc)
+            }
+            IntcodeError::Overflow { pc } => write!(f, "Arithmetic overflow at {}", pc),
+            IntcodeError::InputExhausted { pc } => write!(f, "Ran out of input at {}", pc),
+            IntcodeError::StepLimitExceeded { pc, limit } => {
+                write!(f, "Step limit of {} exceeded at {}", limit, pc)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IntcodeError {}
+
+impl From<IntcodeError> for Error {
+    fn from(err: IntcodeError) -> Error {
+        Error::solve(err.to_string())
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::VecDeque;
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{Receiver, Sender};
+
+/// Where a running program gets its input from and sends its output to.
+pub trait Io {
+    /// The next input value, or `None` if there isn't one yet.
+    fn input(&mut self) -> Option<i64>;
+
+    fn output(&mut self, value: i64);
+}
+
+/// Inputs and outputs held in memory, for programs driven from code.
+#[derive(Debug, Clone, Default)]
+pub struct QueueIo {
+    pub inputs: VecDeque<i64>,
+    pub outputs: VecDeque<i64>,
+}
+
+impl QueueIo {
+    pub fn new(inputs: impl IntoIterator<Item = i64>) -> QueueIo {
+        QueueIo {
+            inputs: inputs.into_iter().collect(),
+            outputs: VecDeque::new(),
+        }
+    }
+}
+
+impl Io for QueueIo {
+    fn input(&mut self) -> Option<i64> {
+        self.inputs.pop_front()
+    }
+
+    fn output(&mut self, value: i64) {
+        self.outputs.push_back(value);
+    }
+}
+
+/// Prompts for each input on stdin and prints outputs to stdout.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdIo;
+
+impl Io for StdIo {
+    fn input(&mut self) -> Option<i64> {
+        print!("> ");
+        let _ = io::stdout().flush();
+
+        let mut line = String::new();
+        io::stdin().lock().read_line(&mut line).ok()?;
+        line.trim().parse().ok()
+    }
+
+    fn output(&mut self, value: i64) {
+        println!(">> {}", value);
+    }
+}
+
+/// Talks to programs that speak ASCII. Each line read becomes its character
+/// codes followed by a newline, and outputs in `0..=127` are written as
+/// characters. Anything bigger, like a final score, is written as a number
+/// on its own line.
+///
+/// Reading from a script file instead of stdin plays a session back; with
+/// `echo` on, the commands show up in the output as if they'd been typed.
+#[derive(Debug)]
+pub struct AsciiIo<R, W> {
+    reader: R,
+    writer: W,
+    pending: VecDeque<i64>,
+    pub echo: bool,
+}
+
+impl<R: BufRead, W: Write> AsciiIo<R, W> {
+    pub fn new(reader: R, writer: W) -> AsciiIo<R, W> {
+        AsciiIo {
+            reader,
+            writer,
+            pending: VecDeque::new(),
+            echo: false,
+        }
+    }
+
+    pub fn scripted(script: R, writer: W) -> AsciiIo<R, W> {
+        AsciiIo {
+            echo: true,
+            ..AsciiIo::new(script, writer)
+        }
+    }
+
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+}
+
+impl<R: BufRead, W: Write> Io for AsciiIo<R, W> {
+    fn input(&mut self) -> Option<i64> {
+        if self.pending.is_empty() {
+            let _ = self.writer.flush();
+
+            let mut line = String::new();
+            if self.reader.read_line(&mut line).ok()? == 0 {
+                return None;
+            }
+            let line = line.trim_end_matches(['\r', '\n']);
+            if self.echo {
+                let _ = writeln!(self.writer, "{}", line);
+            }
+
+            self.pending.extend(line.chars().map(|c| c as i64));
+            self.pending.push_back('\n' as i64);
+        }
+
+        self.pending.pop_front()
+    }
+
+    fn output(&mut self, value: i64) {
+        let _ = match u8::try_from(value) {
+            Ok(c) if c.is_ascii() => write!(self.writer, "{}", c as char),
+            _ => writeln!(self.writer, "{}", value),
+        };
+    }
+}
+
+/// Connects a VM running on its own thread to others. Waiting for input
+/// blocks until a value arrives or every sender has gone away.
+#[derive(Debug)]
+pub struct ChannelIo {
+    pub receiver: Receiver<i64>,
+    pub sender: Sender<i64>,
+}
+
+impl Io for ChannelIo {
+    fn input(&mut self) -> Option<i64> {
+        self.receiver.recv().ok()
+    }
+
+    fn output(&mut self, value: i64) {
+        // The other end hanging up just means nobody wants the rest.
+        let _ = self.sender.send(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+    use crate::vm::{IntcodeVm, VmState};
+
+    #[test]
+    fn ascii_lines_and_large_values() {
+        // Shouts each line back, then prints a score once it sees a blank one.
+        let program = assemble(
+            "
+            loop:   IN [c]
+                    EQ [c], #10, [t]
+                    JNZ [t], #newline
+                    LT [c], #97, [t]
+                    JNZ [t], #print
+                    ADD [c], #-32, [c]
+            print:  OUT [c]
+                    ADD #0, #0, [n]
+                    JNZ #1, #loop
+            newline: OUT #10
+                    JNZ [n], #done
+                    ADD #1, #0, [n]
+                    JNZ #1, #loop
+            done:   OUT #1234
+                    HLT
+            c:      DATA 0
+            t:      DATA 0
+            n:      DATA 1
+            ",
+        )
+        .unwrap();
+
+        let mut io = AsciiIo::new("hi there\r\nok\n\n".as_bytes(), Vec::new());
+        let state = IntcodeVm::new(&program).run_io(&mut io).unwrap();
+        assert_eq!(state, VmState::Halted);
+        assert_eq!(
+            String::from_utf8(io.into_writer()).unwrap(),
+            "HI THERE\nOK\n\n1234\n"
+        );
+
+        let mut io = AsciiIo::scripted("go\n".as_bytes(), Vec::new());
+        let state = IntcodeVm::new(&program).run_io(&mut io).unwrap();
+        assert_eq!(state, VmState::NeedsInput);
+        assert_eq!(String::from_utf8(io.into_writer()).unwrap(), "go\nGO\n");
+    }
+}
@@ -0,0 +1,32 @@
+pub mod asm;
+pub mod compiled;
+pub mod debugger;
+pub mod disasm;
+pub mod error;
+pub mod instruction;
+pub mod io;
+pub mod memory;
+pub mod network;
+pub mod snapshot;
+pub mod trace;
+pub mod vm;
+
+pub use asm::assemble;
+pub use compiled::CompiledVm;
+pub use debugger::Debugger;
+pub use disasm::{disassemble, Disassembly};
+pub use error::IntcodeError;
+pub use instruction::{Instruction, Mode, Opcode};
+pub use io::{AsciiIo, ChannelIo, Io, QueueIo, StdIo};
+pub use memory::{Memory, MemoryError};
+pub use network::{Network, NetworkError, NetworkState, Packet, Topology};
+pub use snapshot::Snapshot;
+pub use trace::{JsonLinesTracer, TraceRecord, TraceSummary, Tracer};
+pub use vm::{IntcodeVm, VmState};
+
+use aoc_common::Result;
+
+/// Parses a comma separated Intcode program.
+pub fn parse_program(s: &str) -> Result<Vec<i64>> {
+    s.trim().split(',').map(|v| Ok(v.trim().parse()?)).collect()
+}
@@ -0,0 +1,195 @@
+use aoc_common::{read_input, Error, Result};
+use intcode::{
+    assemble, disassemble, parse_program, AsciiIo, Debugger, IntcodeError, IntcodeVm,
+    JsonLinesTracer, TraceSummary, VmState,
+};
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "\
+Usage: intcode <COMMAND> <FILE> [OPTIONS]
+
+  ascii FILE       run a program that talks ASCII, typing lines on stdin
+      --script PATH    play the commands in PATH first, then carry on from stdin
+  asm FILE         assemble mnemonic source into a comma separated program
+  debug FILE       step through the program, reading debugger commands from stdin
+  disasm FILE      print the program as mnemonics, marking jump targets and data
+  trace FILE       run the program and write a JSON Lines trace of every instruction
+      --input 1,2,3    values for the program's input instructions
+      --output PATH    write the trace to PATH instead of stdout
+  analyze TRACE    summarize a trace: hot instructions and most written addresses
+      --top N          entries per table (default 10)
+
+FILE may be - to read from stdin. Program output from `trace` goes to stderr.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Ascii,
+    Asm,
+    Analyze,
+    Debug,
+    Disasm,
+    Trace,
+}
+
+#[derive(Debug)]
+struct Args {
+    command: Command,
+    path: String,
+    inputs: Vec<i64>,
+    output: Option<PathBuf>,
+    script: Option<PathBuf>,
+    top: usize,
+}
+
+fn parse_args(args: &[String]) -> std::result::Result<Args, String> {
+    let mut iter = args.iter();
+    let command = match iter.next().map(String::as_str) {
+        Some("ascii") => Command::Ascii,
+        Some("asm") => Command::Asm,
+        Some("analyze") => Command::Analyze,
+        Some("debug") => Command::Debug,
+        Some("disasm") => Command::Disasm,
+        Some("trace") => Command::Trace,
+        Some(command) => return Err(format!("Unknown command {command}")),
+        None => return Err("Missing command".to_string()),
+    };
+    let path = iter.next().ok_or("Missing file")?.clone();
+
+    let mut parsed = Args {
+        command,
+        path,
+        inputs: Vec::new(),
+        output: None,
+        script: None,
+        top: 10,
+    };
+    while let Some(flag) = iter.next() {
+        let value = iter
+            .next()
+            .ok_or_else(|| format!("Missing value for {flag}"))?;
+        let invalid = || format!("Invalid value for {flag}: {value}");
+        match (command, flag.as_str()) {
+            (Command::Trace, "--input") => {
+                parsed.inputs = parse_program(value).map_err(|_| invalid())?;
+            }
+            (Command::Ascii, "--script") => parsed.script = Some(PathBuf::from(value)),
+            (Command::Trace, "--output") => parsed.output = Some(PathBuf::from(value)),
+            (Command::Analyze, "--top") => parsed.top = value.parse().map_err(|_| invalid())?,
+            _ => return Err(format!("Unexpected argument {flag}")),
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = parse_args(&args).unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        eprintln!("{USAGE}");
+        process::exit(2);
+    });
+
+    if let Err(e) = run(args) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+fn io_error(path: impl Into<PathBuf>) -> impl FnOnce(io::Error) -> Error {
+    let path = path.into();
+    move |source| Error::Io { path, source }
+}
+
+fn trace(program: &[i64], inputs: &[i64], writer: impl Write) -> Result<()> {
+    let mut vm = IntcodeVm::new(program);
+    for &value in inputs {
+        vm.push_input(value);
+    }
+
+    let mut tracer = JsonLinesTracer::new(writer);
+    let outcome = loop {
+        match vm.run_traced(&mut tracer) {
+            Ok(VmState::Output(value)) => eprintln!("{value}"),
+            Ok(VmState::Halted) => break Ok(()),
+            Err(e) => break Err(e),
+            Ok(_) => break Err(IntcodeError::InputExhausted { pc: vm.pc() }),
+        }
+    };
+
+    // Keep the trace up to the failing instruction, it's what you want to
+    // look at.
+    tracer.finish().map_err(io_error("trace output"))?;
+    Ok(outcome?)
+}
+
+fn ascii(program: &[i64], script: Option<PathBuf>) -> Result<()> {
+    let mut vm = IntcodeVm::new(program);
+    let mut state = Ok(VmState::NeedsInput);
+    if let Some(script) = script {
+        let file = File::open(&script).map_err(io_error(&script))?;
+        let mut io = AsciiIo::scripted(BufReader::new(file), io::stdout().lock());
+        state = vm.run_io(&mut io);
+    }
+    // Once the script runs out, whoever is at the keyboard takes over.
+    if state == Ok(VmState::NeedsInput) {
+        let mut io = AsciiIo::new(io::stdin().lock(), io::stdout().lock());
+        state = vm.run_io(&mut io);
+    }
+
+    io::stdout().flush().map_err(io_error("stdout"))?;
+    state?;
+    Ok(())
+}
+
+fn run(args: Args) -> Result<()> {
+    let path = args.path;
+    match args.command {
+        Command::Ascii => {
+            let program = parse_program(&read_input(&path)?)?;
+            ascii(&program, args.script)?;
+        }
+        Command::Asm => {
+            let program = assemble(&read_input(&path)?)?;
+            let values: Vec<String> = program.iter().map(|v| v.to_string()).collect();
+            println!("{}", values.join(","));
+        }
+        Command::Analyze => {
+            let summary = TraceSummary::from_reader(read_input(&path)?.as_bytes())?;
+            summary
+                .write_report(args.top, &mut io::stdout().lock())
+                .map_err(io_error("stdout"))?;
+        }
+        Command::Debug => {
+            let program = parse_program(&read_input(&path)?)?;
+            Debugger::new(&program)
+                .repl(io::stdin().lock(), io::stdout().lock())
+                .map_err(io_error("stdin"))?;
+        }
+        Command::Disasm => {
+            let program = parse_program(&read_input(&path)?)?;
+            let disassembly = disassemble(&program);
+            print!("{disassembly}");
+            for (address, value) in &disassembly.invalid {
+                eprintln!("Invalid opcode {value} at {address}");
+            }
+        }
+        Command::Trace => {
+            let program = parse_program(&read_input(&path)?)?;
+            match args.output {
+                Some(output) => {
+                    let file = File::create(&output).map_err(io_error(&output))?;
+                    trace(&program, &args.inputs, BufWriter::new(file))?;
+                }
+                None => trace(&program, &args.inputs, io::stdout().lock())?,
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,244 @@
+//! Paged memory. Programs can address far past their own length (day09's
+//! BOOST writes well beyond the end), so memory is split into fixed-size
+//! pages that are only allocated once something is written to them.
+//!
+//! Pages are shared between clones and copied on the first write, which is
+//! what makes VM snapshots cheap.
+
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+use std::sync::Arc;
+
+const PAGE_BITS: u32 = 10;
+const PAGE_SIZE: usize = 1 << PAGE_BITS;
+
+type Page = [i64; PAGE_SIZE];
+
+/// Programs can't write past this. Anything further out is a runaway
+/// address, and growing the page table to reach it could exhaust memory.
+pub const MAX_ADDRESS: usize = 1 << 26;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryError {
+    NegativeAddress(i64),
+    AddressTooLarge(i64),
+}
+
+impl fmt::Display for MemoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MemoryError::NegativeAddress(address) => write!(f, "Negative address {}", address),
+            MemoryError::AddressTooLarge(address) => write!(f, "Address {} out of range", address),
+        }
+    }
+}
+
+impl std::error::Error for MemoryError {}
+
+/// Reads of cells that were never written return 0; writes allocate pages
+/// as needed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(into = "SparsePages", try_from = "SparsePages")]
+pub struct Memory {
+    pages: Vec<Option<Arc<Page>>>,
+    len: usize,
+}
+
+/// The serialized form: only the pages that were allocated.
+#[derive(Serialize, Deserialize)]
+struct SparsePages {
+    len: usize,
+    pages: Vec<(usize, Vec<i64>)>,
+}
+
+impl From<Memory> for SparsePages {
+    fn from(memory: Memory) -> SparsePages {
+        let pages = memory
+            .pages
+            .iter()
+            .enumerate()
+            .filter_map(|(i, page)| Some((i, page.as_ref()?.to_vec())))
+            .collect();
+        SparsePages {
+            len: memory.len,
+            pages,
+        }
+    }
+}
+
+impl TryFrom<SparsePages> for Memory {
+    type Error = String;
+
+    fn try_from(sparse: SparsePages) -> Result<Memory, String> {
+        if sparse.len > MAX_ADDRESS {
+            return Err(format!("Memory length {} out of range", sparse.len));
+        }
+
+        let mut memory = Memory {
+            pages: Vec::new(),
+            len: sparse.len,
+        };
+        for (i, values) in sparse.pages {
+            // Every allocated page holds something below `len`.
+            if i >= MAX_ADDRESS >> PAGE_BITS || i << PAGE_BITS >= sparse.len {
+                return Err(format!(
+                    "Page {} is past the memory length {}",
+                    i, sparse.len
+                ));
+            }
+            let page: Page = values
+                .try_into()
+                .map_err(|v: Vec<i64>| format!("Page {} has {} values", i, v.len()))?;
+            if i >= memory.pages.len() {
+                memory.pages.resize(i + 1, None);
+            }
+            if memory.pages[i].replace(Arc::new(page)).is_some() {
+                return Err(format!("Page {} appears twice", i));
+            }
+        }
+
+        // And the last value written is on an allocated page.
+        if memory.len > memory.pages.len() << PAGE_BITS {
+            return Err(format!(
+                "Memory length {} is past the last page",
+                memory.len
+            ));
+        }
+
+        Ok(memory)
+    }
+}
+
+fn split(address: usize) -> (usize, usize) {
+    (address >> PAGE_BITS, address & (PAGE_SIZE - 1))
+}
+
+fn check(address: i64) -> Result<usize, MemoryError> {
+    usize::try_from(address).map_err(|_| MemoryError::NegativeAddress(address))
+}
+
+impl Memory {
+    pub fn new(program: &[i64]) -> Memory {
+        let mut memory = Memory::default();
+        for (address, &value) in program.iter().enumerate() {
+            memory.set(address, value);
+        }
+        memory
+    }
+
+    /// One past the highest address that has been written.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, address: usize) -> i64 {
+        let (page, offset) = split(address);
+        match self.pages.get(page) {
+            Some(Some(page)) => page[offset],
+            _ => 0,
+        }
+    }
+
+    pub fn set(&mut self, address: usize, value: i64) {
+        let (page, offset) = split(address);
+        if page >= self.pages.len() {
+            self.pages.resize(page + 1, None);
+        }
+
+        let page = self.pages[page].get_or_insert_with(|| Arc::new([0; PAGE_SIZE]));
+        Arc::make_mut(page)[offset] = value;
+        self.len = self.len.max(address + 1);
+    }
+
+    /// Reads an address computed by the program.
+    pub fn read(&self, address: i64) -> Result<i64, MemoryError> {
+        Ok(self.get(check(address)?))
+    }
+
+    /// Writes an address computed by the program. Unlike reads, writes
+    /// allocate, so they're limited to `MAX_ADDRESS`.
+    pub fn write(&mut self, address: i64, value: i64) -> Result<(), MemoryError> {
+        match check(address)? {
+            index if index >= MAX_ADDRESS => Err(MemoryError::AddressTooLarge(address)),
+            index => {
+                self.set(index, value);
+                Ok(())
+            }
+        }
+    }
+
+    /// Everything up to `len`, including unwritten cells as 0.
+    pub fn to_vec(&self) -> Vec<i64> {
+        (0..self.len).map(|a| self.get(a)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn out_of_range_semantics() {
+        let mut memory = Memory::new(&[1, 2, 3]);
+        assert_eq!(memory.read(2), Ok(3));
+        assert_eq!(memory.read(1_000_000), Ok(0));
+        assert_eq!(memory.len(), 3);
+
+        memory.write(5000, 7).unwrap();
+        assert_eq!(memory.read(5000), Ok(7));
+        assert_eq!(memory.read(4999), Ok(0));
+        assert_eq!(memory.len(), 5001);
+
+        assert_eq!(memory.read(-1), Err(MemoryError::NegativeAddress(-1)));
+        assert_eq!(memory.write(-4, 1), Err(MemoryError::NegativeAddress(-4)));
+        assert_eq!(
+            memory.write(i64::MAX, 1),
+            Err(MemoryError::AddressTooLarge(i64::MAX))
+        );
+        assert_eq!(memory.read(i64::MAX), Ok(0));
+    }
+
+    #[test]
+    fn clones_share_pages_until_written() {
+        let mut memory = Memory::new(&[1, 2, 3]);
+        memory.set(3000, 4);
+        let copy = memory.clone();
+        memory.set(1, 20);
+
+        assert_eq!(copy.get(1), 2);
+        assert_eq!(memory.get(1), 20);
+        assert!(Arc::ptr_eq(
+            memory.pages[2].as_ref().unwrap(),
+            copy.pages[2].as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_snapshots() {
+        let mut memory = Memory::new(&[1, 2, 3]);
+        memory.set(3000, 4);
+        let json = serde_json::to_string(&memory).unwrap();
+        let loaded: Memory = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            (loaded.len(), loaded.get(1), loaded.get(3000)),
+            (3001, 2, 4)
+        );
+
+        let page = format!("[{}]", vec!["0"; PAGE_SIZE].join(","));
+        for json in [
+            format!(r#"{{"len":3,"pages":[[{},{}]]}}"#, usize::MAX, page),
+            format!(r#"{{"len":{},"pages":[[0,{}]]}}"#, MAX_ADDRESS + 1, page),
+            format!(r#"{{"len":3,"pages":[[1,{}]]}}"#, page),
+            format!(r#"{{"len":3,"pages":[[0,{}],[0,{}]]}}"#, page, page),
+            r#"{"len":3,"pages":[]}"#.to_string(),
+            r#"{"len":3,"pages":[[0,[1,2,3]]]}"#.to_string(),
+        ] {
+            assert!(serde_json::from_str::<Memory>(&json).is_err(), "{}", json);
+        }
+    }
+}
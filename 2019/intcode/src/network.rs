@@ -0,0 +1,322 @@
+//! Several VMs wired together, with a round-robin scheduler that gives each
+//! VM a turn until it blocks on input, delivering its outputs as they come.
+
+use crate::error::IntcodeError;
+use crate::vm::{IntcodeVm, VmState};
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    /// Each VM feeds the next. Whatever the last one prints leaves the
+    /// network as a single-value packet.
+    Chain,
+    /// A chain whose last VM feeds back into the first.
+    Ring,
+    /// VMs print packets of `destination, values...` with `payload` values.
+    /// Packets for an address that isn't a VM leave the network. With an
+    /// `idle_input`, a VM asking for input with nothing queued gets that
+    /// value instead of blocking, like day 23's -1.
+    Bus {
+        payload: usize,
+        idle_input: Option<i64>,
+    },
+}
+
+/// Values that left the network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub source: usize,
+    pub destination: i64,
+    pub values: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkState {
+    /// Every VM has halted.
+    Halted,
+    /// A bus with an idle input went a whole round without sending anything
+    /// while every VM was polling for input.
+    Idle,
+    /// Every VM still running is waiting for input that nothing is going to
+    /// send. `send` more and `run` again to carry on.
+    Deadlocked,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkError {
+    pub vm: usize,
+    pub error: IntcodeError,
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "VM {}: {}", self.vm, self.error)
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+impl From<NetworkError> for aoc_common::Error {
+    fn from(err: NetworkError) -> aoc_common::Error {
+        aoc_common::Error::solve(err.to_string())
+    }
+}
+
+pub struct Network {
+    vms: Vec<IntcodeVm>,
+    topology: Topology,
+    halted: Vec<bool>,
+    /// Bus output that doesn't make up a whole packet yet, per VM.
+    partial: Vec<Vec<i64>>,
+    last_outputs: Vec<Option<i64>>,
+    outputs: Vec<Packet>,
+}
+
+impl Network {
+    pub fn new(vms: Vec<IntcodeVm>, topology: Topology) -> Network {
+        let count = vms.len();
+        Network {
+            vms,
+            topology,
+            halted: vec![false; count],
+            partial: vec![Vec::new(); count],
+            last_outputs: vec![None; count],
+            outputs: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.vms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vms.is_empty()
+    }
+
+    pub fn vm(&self, index: usize) -> &IntcodeVm {
+        &self.vms[index]
+    }
+
+    /// Queues a value for one of the VMs from outside the network.
+    pub fn send(&mut self, index: usize, value: i64) {
+        self.vms[index].push_input(value);
+    }
+
+    /// The most recent value VM `index` printed.
+    pub fn last_output(&self, index: usize) -> Option<i64> {
+        self.last_outputs[index]
+    }
+
+    /// Packets that left the network, oldest first.
+    pub fn outputs(&self) -> &[Packet] {
+        &self.outputs
+    }
+
+    pub fn take_outputs(&mut self) -> Vec<Packet> {
+        std::mem::take(&mut self.outputs)
+    }
+
+    /// Delivers a value printed by VM `source`. Returns whether it went to
+    /// another VM.
+    fn route(&mut self, source: usize, value: i64) -> bool {
+        self.last_outputs[source] = Some(value);
+        let count = self.vms.len();
+
+        match self.topology {
+            Topology::Chain | Topology::Ring if source + 1 < count => {
+                self.vms[source + 1].push_input(value);
+                true
+            }
+            Topology::Ring => {
+                self.vms[0].push_input(value);
+                true
+            }
+            Topology::Chain => {
+                self.outputs.push(Packet {
+                    source,
+                    destination: count as i64,
+                    values: vec![value],
+                });
+                false
+            }
+            Topology::Bus { payload, .. } => {
+                self.partial[source].push(value);
+                if self.partial[source].len() < payload + 1 {
+                    return false;
+                }
+
+                let packet = std::mem::take(&mut self.partial[source]);
+                let destination = packet[0];
+                match usize::try_from(destination) {
+                    Ok(target) if target < count => {
+                        for &value in &packet[1..] {
+                            self.vms[target].push_input(value);
+                        }
+                        true
+                    }
+                    _ => {
+                        self.outputs.push(Packet {
+                            source,
+                            destination,
+                            values: packet[1..].to_vec(),
+                        });
+                        false
+                    }
+                }
+            }
+        }
+    }
+
+    /// Gives VM `index` a turn: it runs until it wants input that isn't
+    /// there, or halts. Returns whether it sent anything to another VM.
+    fn turn(&mut self, index: usize) -> Result<bool, NetworkError> {
+        let idle_input = match self.topology {
+            Topology::Bus { idle_input, .. } => idle_input,
+            _ => None,
+        };
+
+        let mut sent = false;
+        let mut polled = false;
+        loop {
+            let state = self.vms[index]
+                .run()
+                .map_err(|error| NetworkError { vm: index, error })?;
+            match state {
+                VmState::Output(value) => sent |= self.route(index, value),
+                VmState::Halted => {
+                    self.halted[index] = true;
+                    return Ok(sent);
+                }
+                VmState::NeedsInput => match idle_input {
+                    // One idle value per turn, so a polling VM can't spin forever.
+                    Some(value) if !polled => {
+                        self.vms[index].push_input(value);
+                        polled = true;
+                    }
+                    _ => return Ok(sent),
+                },
+                VmState::Running => unreachable!("run never returns Running"),
+            }
+        }
+    }
+
+    /// Schedules the VMs round-robin until they've all halted, the bus goes
+    /// idle, or nothing can make progress.
+    pub fn run(&mut self) -> Result<NetworkState, NetworkError> {
+        loop {
+            let mut sent = false;
+            for index in 0..self.vms.len() {
+                if !self.halted[index] {
+                    sent |= self.turn(index)?;
+                }
+            }
+
+            if self.halted.iter().all(|&h| h) {
+                return Ok(NetworkState::Halted);
+            }
+
+            let waiting = (0..self.vms.len())
+                .filter(|&i| !self.halted[i])
+                .all(|i| self.vms[i].pending_inputs().is_empty());
+            if !sent && waiting {
+                return Ok(match self.topology {
+                    Topology::Bus {
+                        idle_input: Some(_),
+                        ..
+                    } => NetworkState::Idle,
+                    _ => NetworkState::Deadlocked,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    fn vms(source: &str, count: usize) -> Vec<IntcodeVm> {
+        let program = assemble(source).unwrap();
+        (0..count).map(|_| IntcodeVm::new(&program)).collect()
+    }
+
+    const ADD_ONE: &str = "
+        loop:   IN [x]
+                ADD [x], #1, [x]
+                OUT [x]
+                JNZ #1, #loop
+        x:      DATA 0
+    ";
+
+    #[test]
+    fn chain_and_deadlock() {
+        let mut network = Network::new(vms(ADD_ONE, 3), Topology::Chain);
+        network.send(0, 10);
+        network.send(0, 20);
+
+        assert_eq!(network.run(), Ok(NetworkState::Deadlocked));
+        let outputs: Vec<i64> = network.take_outputs().iter().map(|p| p.values[0]).collect();
+        assert_eq!(outputs, [13, 23]);
+    }
+
+    #[test]
+    fn ring_until_halted() {
+        // Adds one and passes it on, halting once the value reaches 10.
+        let source = "
+            loop:   IN [x]
+                    ADD [x], #1, [x]
+                    OUT [x]
+                    LT [x], #10, [t]
+                    JNZ [t], #loop
+                    HLT
+            x:      DATA 0
+            t:      DATA 0
+        ";
+        let mut network = Network::new(vms(source, 3), Topology::Ring);
+        network.send(0, 0);
+
+        assert_eq!(network.run(), Ok(NetworkState::Halted));
+        assert_eq!(network.last_output(0), Some(10));
+    }
+
+    #[test]
+    fn bus_packets_and_idle() {
+        // Reads its address; VM 0 sends [1, 7] to VM 1, which forwards
+        // [x * 2] to address 255. Afterwards everyone polls forever.
+        let source = "
+                    IN [address]
+                    JNZ [address], #poll
+                    OUT #1
+                    OUT #7
+            poll:   IN [x]
+                    EQ [x], #-1, [t]
+                    JNZ [t], #poll
+                    MUL [x], #2, [x]
+                    OUT #255
+                    OUT [x]
+                    JNZ #1, #poll
+            address: DATA 0
+            x:      DATA 0
+            t:      DATA 0
+        ";
+        let topology = Topology::Bus {
+            payload: 1,
+            idle_input: Some(-1),
+        };
+        let mut network = Network::new(vms(source, 2), topology);
+        network.send(0, 0);
+        network.send(1, 1);
+
+        assert_eq!(network.run(), Ok(NetworkState::Idle));
+        assert_eq!(
+            network.outputs(),
+            [Packet {
+                source: 1,
+                destination: 255,
+                values: vec![14]
+            }]
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Saved VM states. Taking one shares memory pages with the running VM, so
+//! it costs about as much as cloning the page table, and they can be written
+//! to disk as JSON to pick a long exploration back up later.
+
+use crate::memory::Memory;
+
+use aoc_common::{Error, Result};
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub(crate) memory: Memory,
+    pub(crate) pc: usize,
+    pub(crate) relative_base: i64,
+    pub(crate) inputs: VecDeque<i64>,
+    pub(crate) steps: u64,
+}
+
+impl Snapshot {
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_string(self).map_err(|e| Error::parse(e.to_string()))?;
+        fs::write(path, json).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Snapshot> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        serde_json::from_str(&json).map_err(|e| Error::parse(format!("{}: {}", path.display(), e)))
+    }
+}
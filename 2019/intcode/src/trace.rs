@@ -0,0 +1,298 @@
+//! Execution traces as JSON Lines, one record per executed instruction, and
+//! a summary of where a program spends its time.
+
+use crate::instruction::{Mode, Opcode};
+
+use aoc_common::{Error, Result};
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IoEvent {
+    Input(i64),
+    Output(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryWrite {
+    pub address: i64,
+    pub value: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceRecord {
+    /// Instructions executed before this one.
+    pub step: u64,
+    pub pc: usize,
+    pub opcode: Opcode,
+    pub modes: Vec<Mode>,
+    /// Parameter values after applying their modes. The destination of a
+    /// write is given as the address it resolved to, and the target of a
+    /// jump not taken is left out since it was never read.
+    pub operands: Vec<i64>,
+    pub writes: Vec<MemoryWrite>,
+    /// The relative base the operands were resolved against.
+    pub relative_base: i64,
+    pub io: Option<IoEvent>,
+}
+
+/// Receives a record for every instruction the VM executes.
+pub trait Tracer {
+    fn record(&mut self, record: &TraceRecord);
+
+    /// The VM skips building records when this is false.
+    fn enabled(&self) -> bool {
+        true
+    }
+}
+
+/// The do-nothing tracer used by `IntcodeVm::step` and `run`.
+impl Tracer for () {
+    fn record(&mut self, _record: &TraceRecord) {}
+
+    fn enabled(&self) -> bool {
+        false
+    }
+}
+
+impl Tracer for Vec<TraceRecord> {
+    fn record(&mut self, record: &TraceRecord) {
+        self.push(record.clone());
+    }
+}
+
+/// Writes each record as a line of JSON. The first write error is kept and
+/// later records are dropped; check it with `finish`.
+pub struct JsonLinesTracer<W: Write> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> JsonLinesTracer<W> {
+    pub fn new(writer: W) -> JsonLinesTracer<W> {
+        JsonLinesTracer {
+            writer,
+            error: None,
+        }
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        match self.error {
+            Some(e) => Err(e),
+            None => {
+                self.writer.flush()?;
+                Ok(self.writer)
+            }
+        }
+    }
+}
+
+impl<W: Write> Tracer for JsonLinesTracer<W> {
+    fn record(&mut self, record: &TraceRecord) {
+        if self.error.is_some() {
+            return;
+        }
+
+        let result = serde_json::to_writer(&mut self.writer, record)
+            .map_err(io::Error::from)
+            .and_then(|_| self.writer.write_all(b"\n"));
+        if let Err(e) = result {
+            self.error = Some(e);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HotInstruction {
+    pub pc: usize,
+    pub opcode: Opcode,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TraceSummary {
+    pub steps: u64,
+    pub inputs: u64,
+    pub outputs: u64,
+    pc_counts: HashMap<usize, (Opcode, u64)>,
+    write_counts: HashMap<i64, u64>,
+}
+
+impl TraceSummary {
+    pub fn add(&mut self, record: &TraceRecord) {
+        self.steps += 1;
+        match record.io {
+            Some(IoEvent::Input(_)) => self.inputs += 1,
+            Some(IoEvent::Output(_)) => self.outputs += 1,
+            None => {}
+        }
+
+        self.pc_counts
+            .entry(record.pc)
+            .or_insert((record.opcode, 0))
+            .1 += 1;
+        for write in &record.writes {
+            *self.write_counts.entry(write.address).or_default() += 1;
+        }
+    }
+
+    /// The `n` most executed instructions, busiest first.
+    pub fn hot_instructions(&self, n: usize) -> Vec<HotInstruction> {
+        let mut hot: Vec<HotInstruction> = self
+            .pc_counts
+            .iter()
+            .map(|(&pc, &(opcode, count))| HotInstruction { pc, opcode, count })
+            .collect();
+        hot.sort_by_key(|h| (std::cmp::Reverse(h.count), h.pc));
+        hot.truncate(n);
+        hot
+    }
+
+    /// The `n` most written addresses with their write counts.
+    pub fn write_hotspots(&self, n: usize) -> Vec<(i64, u64)> {
+        let mut hot: Vec<(i64, u64)> = self.write_counts.iter().map(|(&a, &c)| (a, c)).collect();
+        hot.sort_by_key(|&(address, count)| (std::cmp::Reverse(count), address));
+        hot.truncate(n);
+        hot
+    }
+
+    /// Reads a JSON Lines trace, skipping blank lines.
+    pub fn from_reader(reader: impl BufRead) -> Result<TraceSummary> {
+        let mut summary = TraceSummary::default();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line.map_err(|e| Error::parse(e.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let record: TraceRecord = serde_json::from_str(&line)
+                .map_err(|e| Error::parse(format!("trace line {}: {}", i + 1, e)))?;
+            summary.add(&record);
+        }
+
+        Ok(summary)
+    }
+
+    /// Writes the step counts and the top `n` entries of each table.
+    pub fn write_report(&self, n: usize, out: &mut impl Write) -> io::Result<()> {
+        writeln!(
+            out,
+            "{} steps, {} inputs, {} outputs",
+            self.steps, self.inputs, self.outputs
+        )?;
+
+        writeln!(out, "\nHot instructions:")?;
+        for hot in self.hot_instructions(n) {
+            let share = hot.count as f64 * 100.0 / self.steps as f64;
+            writeln!(
+                out,
+                "  {:>6}  {:<4} {:>10}  {:5.1}%",
+                hot.pc,
+                hot.opcode.mnemonic(),
+                hot.count,
+                share
+            )?;
+        }
+
+        writeln!(out, "\nMost written addresses:")?;
+        for (address, count) in self.write_hotspots(n) {
+            writeln!(out, "  {:>6}  {:>10}", address, count)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::IntcodeVm;
+
+    #[test]
+    fn records_operands_writes_and_io() {
+        // IN [rb+9], ADD [9], #1, [9], OUT [9], HLT, with rb = 0.
+        let mut vm = IntcodeVm::new(&[203, 9, 1001, 9, 1, 9, 4, 9, 99, 0]);
+        vm.push_input(41);
+        let mut records: Vec<TraceRecord> = Vec::new();
+        vm.run_traced(&mut records).unwrap();
+        vm.run_traced(&mut records).unwrap();
+
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0].modes, [Mode::Relative]);
+        assert_eq!(records[0].io, Some(IoEvent::Input(41)));
+        assert_eq!(records[1].operands, [41, 1, 9]);
+        assert_eq!(
+            records[1].writes,
+            [MemoryWrite {
+                address: 9,
+                value: 42
+            }]
+        );
+        assert_eq!(records[2].io, Some(IoEvent::Output(42)));
+        assert_eq!(records[3].opcode, Opcode::Halt);
+        assert_eq!(records[3].step, 3);
+    }
+
+    #[test]
+    fn tracing_doesnt_change_execution() {
+        let programs: [&[i64]; 6] = [
+            // JNZ #0, [5]: not taken, so the negative target is never read.
+            &[5, 5, -1, 99, 0, 0],
+            // JZ #0, #-1: taken this time.
+            &[1106, 0, -1],
+            &[203, 9, 1001, 9, 1, 9, 4, 9, 99, 0],
+            &[1001, 9, -1, 9, 1005, 9, 0, 99, 0, 3],
+            // IN [-1] fails only once there is an input to store.
+            &[3, -1, 99],
+            &[1, -1, 0, 0],
+        ];
+        for program in programs {
+            for inputs in [&[][..], &[41]] {
+                let mut plain = IntcodeVm::new(program);
+                let mut traced = IntcodeVm::new(program);
+                let mut records: Vec<TraceRecord> = Vec::new();
+                for &value in inputs {
+                    plain.push_input(value);
+                    traced.push_input(value);
+                }
+
+                for _ in 0..4 {
+                    assert_eq!(
+                        plain.run(),
+                        traced.run_traced(&mut records),
+                        "{:?}",
+                        program
+                    );
+                    assert_eq!(plain.pc(), traced.pc());
+                    assert_eq!(plain.steps(), traced.steps());
+                }
+                let halts = records.iter().filter(|r| r.opcode == Opcode::Halt);
+                assert!(halts.count() <= 1, "{:?}", program);
+            }
+        }
+    }
+
+    #[test]
+    fn summary_from_json_lines() {
+        // Counts down from 3: ADD [9], #-1, [9], JNZ [9], #0, HLT
+        let program = [1001, 9, -1, 9, 1005, 9, 0, 99, 0, 3];
+        let mut tracer = JsonLinesTracer::new(Vec::new());
+        IntcodeVm::new(&program).run_traced(&mut tracer).unwrap();
+        let trace = tracer.finish().unwrap();
+
+        let summary = TraceSummary::from_reader(&trace[..]).unwrap();
+        assert_eq!(summary.steps, 7);
+        assert_eq!(
+            summary.hot_instructions(1),
+            [HotInstruction {
+                pc: 0,
+                opcode: Opcode::Add,
+                count: 3
+            }]
+        );
+        assert_eq!(summary.write_hotspots(5), [(9, 3)]);
+    }
+}
@@ -0,0 +1,577 @@
+use crate::error::IntcodeError;
+use crate::instruction::{Instruction, Mode, Opcode};
+use crate::io::Io;
+use crate::memory::{Memory, MemoryError};
+use crate::snapshot::Snapshot;
+use crate::trace::{IoEvent, MemoryWrite, TraceRecord, Tracer};
+
+use std::collections::VecDeque;
+
+/// Where the machine stopped. `run` never returns `Running`; that only comes
+/// back from `step` when the instruction finished without needing attention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VmState {
+    Running,
+    NeedsInput,
+    Output(i64),
+    Halted,
+}
+
+#[derive(Debug, Clone)]
+pub struct IntcodeVm {
+    pub(crate) memory: Memory,
+    pub(crate) pc: usize,
+    pub(crate) relative_base: i64,
+    pub(crate) inputs: VecDeque<i64>,
+    pub(crate) steps: u64,
+    pub(crate) step_limit: Option<u64>,
+    /// Set by a `Halt`, so running a halted machine again isn't traced as
+    /// another one.
+    halted: bool,
+}
+
+/// Throws away the operands `execute` resolves when nobody is tracing.
+struct Discard;
+
+impl Extend<i64> for Discard {
+    fn extend<I: IntoIterator<Item = i64>>(&mut self, _values: I) {}
+}
+
+impl IntcodeVm {
+    pub fn new(program: &[i64]) -> IntcodeVm {
+        IntcodeVm {
+            memory: Memory::new(program),
+            pc: 0,
+            relative_base: 0,
+            inputs: VecDeque::new(),
+            steps: 0,
+            step_limit: None,
+            halted: false,
+        }
+    }
+
+    /// Captures the machine's state. Memory is shared with the VM until one
+    /// of them writes to it, so this is cheap even for large programs.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            memory: self.memory.clone(),
+            pc: self.pc,
+            relative_base: self.relative_base,
+            inputs: self.inputs.clone(),
+            steps: self.steps,
+        }
+    }
+
+    /// Puts the machine back into a saved state. The step limit is kept.
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.memory = snapshot.memory.clone();
+        self.pc = snapshot.pc;
+        self.relative_base = snapshot.relative_base;
+        self.inputs = snapshot.inputs.clone();
+        self.steps = snapshot.steps;
+        self.halted = false;
+    }
+
+    pub fn from_snapshot(snapshot: &Snapshot) -> IntcodeVm {
+        let mut vm = IntcodeVm::new(&[]);
+        vm.restore(snapshot);
+        vm
+    }
+
+    /// Queues a value for the program's next input instruction.
+    pub fn push_input(&mut self, value: i64) {
+        self.inputs.push_back(value);
+    }
+
+    /// Inputs that have been queued but not read yet.
+    pub fn pending_inputs(&self) -> &VecDeque<i64> {
+        &self.inputs
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Number of instructions executed so far.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Fails with `StepLimitExceeded` once `limit` instructions have run,
+    /// for programs that might never halt.
+    pub fn set_step_limit(&mut self, limit: u64) {
+        self.step_limit = Some(limit);
+    }
+
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
+
+    pub fn memory(&self) -> &Memory {
+        &self.memory
+    }
+
+    /// Memory past the end of the program reads as 0.
+    pub fn read(&self, address: usize) -> i64 {
+        self.memory.get(address)
+    }
+
+    /// Writing past the end of the program grows memory, up to
+    /// `MAX_ADDRESS` like the program's own writes.
+    pub fn write(&mut self, address: usize, value: i64) -> Result<(), IntcodeError> {
+        self.store(i64::try_from(address).unwrap_or(i64::MAX), value)
+    }
+
+    fn memory_error(&self, error: MemoryError) -> IntcodeError {
+        match error {
+            MemoryError::NegativeAddress(address) => IntcodeError::NegativeAddress {
+                pc: self.pc,
+                address,
+            },
+            MemoryError::AddressTooLarge(address) => IntcodeError::AddressTooLarge {
+                pc: self.pc,
+                address,
+            },
+        }
+    }
+
+    /// Checks the result of the program's arithmetic.
+    pub(crate) fn checked(&self, value: Option<i64>) -> Result<i64, IntcodeError> {
+        value.ok_or(IntcodeError::Overflow { pc: self.pc })
+    }
+
+    /// Reads an address the program computed, which may be negative.
+    pub(crate) fn load(&self, address: i64) -> Result<i64, IntcodeError> {
+        self.memory.read(address).map_err(|e| self.memory_error(e))
+    }
+
+    pub(crate) fn store(&mut self, address: i64, value: i64) -> Result<(), IntcodeError> {
+        self.memory
+            .write(address, value)
+            .map_err(|e| self.memory_error(e))
+    }
+
+    pub fn current_instruction(&self) -> Result<Instruction, IntcodeError> {
+        let value = self.read(self.pc);
+        Instruction::decode(value).ok_or(match Opcode::from_code(value % 100) {
+            Some(_) => IntcodeError::InvalidMode { pc: self.pc, value },
+            None => IntcodeError::InvalidOpcode { pc: self.pc, value },
+        })
+    }
+
+    fn address(&self, instruction: &Instruction, index: usize) -> Result<i64, IntcodeError> {
+        let raw = self.read(self.pc + 1 + index);
+        match instruction.modes[index] {
+            Mode::Position => Ok(raw),
+            Mode::Relative => self.checked(self.relative_base.checked_add(raw)),
+            Mode::Immediate => Err(IntcodeError::WriteInImmediateMode { pc: self.pc }),
+        }
+    }
+
+    fn parameter(&self, instruction: &Instruction, index: usize) -> Result<i64, IntcodeError> {
+        match instruction.modes[index] {
+            Mode::Immediate => Ok(self.read(self.pc + 1 + index)),
+            _ => self.load(self.address(instruction, index)?),
+        }
+    }
+
+    pub(crate) fn jump(&mut self, target: i64) -> Result<(), IntcodeError> {
+        self.pc = usize::try_from(target).map_err(|_| IntcodeError::NegativeAddress {
+            pc: self.pc,
+            address: target,
+        })?;
+        Ok(())
+    }
+
+    /// Executes a single instruction. Asking for input with nothing queued
+    /// leaves the machine untouched so it can be resumed after `push_input`,
+    /// and so does an instruction that fails.
+    pub fn step(&mut self) -> Result<VmState, IntcodeError> {
+        self.step_traced(&mut ())
+    }
+
+    /// Like `step`, reporting the instruction to `tracer` if it executed.
+    pub fn step_traced(&mut self, tracer: &mut impl Tracer) -> Result<VmState, IntcodeError> {
+        if !tracer.enabled() {
+            return self.execute(&mut Discard);
+        }
+
+        let pc = self.pc;
+        let relative_base = self.relative_base;
+        let instruction = self.current_instruction()?;
+        let input = self.inputs.front().copied();
+        let step = self.steps;
+        let halted = self.halted;
+
+        let mut operands = Vec::new();
+        let state = self.execute(&mut operands)?;
+        if state == VmState::NeedsInput || (state == VmState::Halted && halted) {
+            return Ok(state);
+        }
+
+        let io = match (state, input) {
+            (VmState::Output(value), _) => Some(IoEvent::Output(value)),
+            (_, Some(value)) if instruction.opcode == Opcode::Input => Some(IoEvent::Input(value)),
+            _ => None,
+        };
+        let writes = match operands.last() {
+            Some(&address) if instruction.opcode.writes() => vec![MemoryWrite {
+                address,
+                value: self.load(address)?,
+            }],
+            _ => Vec::new(),
+        };
+
+        tracer.record(&TraceRecord {
+            step,
+            pc,
+            opcode: instruction.opcode,
+            modes: instruction.modes[..instruction.opcode.parameter_count()].to_vec(),
+            operands,
+            writes,
+            relative_base,
+            io,
+        });
+
+        Ok(state)
+    }
+
+    /// Runs the current instruction, passing each operand to `resolved` as
+    /// it's read: parameter values, then the address of a write.
+    fn execute(&mut self, resolved: &mut impl Extend<i64>) -> Result<VmState, IntcodeError> {
+        let instruction = self.current_instruction()?;
+        if let Some(limit) = self.step_limit {
+            if self.steps >= limit && instruction.opcode != Opcode::Halt {
+                return Err(IntcodeError::StepLimitExceeded { pc: self.pc, limit });
+            }
+        }
+        let next = self.pc + instruction.size();
+
+        let mut state = VmState::Running;
+        match instruction.opcode {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => {
+                let a = self.parameter(&instruction, 0)?;
+                let b = self.parameter(&instruction, 1)?;
+                resolved.extend([a, b]);
+                let value = match instruction.opcode {
+                    Opcode::Add => self.checked(a.checked_add(b))?,
+                    Opcode::Multiply => self.checked(a.checked_mul(b))?,
+                    Opcode::LessThan => (a < b) as i64,
+                    _ => (a == b) as i64,
+                };
+                let target = self.address(&instruction, 2)?;
+                resolved.extend([target]);
+                self.store(target, value)?;
+                self.pc = next;
+            }
+            Opcode::Input => {
+                let Some(&value) = self.inputs.front() else {
+                    return Ok(VmState::NeedsInput);
+                };
+                let target = self.address(&instruction, 0)?;
+                resolved.extend([target]);
+                self.store(target, value)?;
+                self.inputs.pop_front();
+                self.pc = next;
+            }
+            Opcode::Output => {
+                let value = self.parameter(&instruction, 0)?;
+                resolved.extend([value]);
+                self.pc = next;
+                state = VmState::Output(value);
+            }
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let condition = self.parameter(&instruction, 0)?;
+                resolved.extend([condition]);
+                if (condition != 0) == (instruction.opcode == Opcode::JumpIfTrue) {
+                    let target = self.parameter(&instruction, 1)?;
+                    resolved.extend([target]);
+                    self.jump(target)?;
+                } else {
+                    self.pc = next;
+                }
+            }
+            Opcode::AdjustRelativeBase => {
+                let offset = self.parameter(&instruction, 0)?;
+                resolved.extend([offset]);
+                self.relative_base = self.checked(self.relative_base.checked_add(offset))?;
+                self.pc = next;
+            }
+            Opcode::Halt => {
+                self.halted = true;
+                return Ok(VmState::Halted);
+            }
+        }
+
+        self.steps += 1;
+        self.halted = false;
+        Ok(state)
+    }
+
+    /// Runs until the program produces an output, needs input it doesn't
+    /// have, or halts. Calling it again picks up where it stopped.
+    pub fn run(&mut self) -> Result<VmState, IntcodeError> {
+        self.run_traced(&mut ())
+    }
+
+    /// Like `run`, reporting every executed instruction to `tracer`.
+    pub fn run_traced(&mut self, tracer: &mut impl Tracer) -> Result<VmState, IntcodeError> {
+        loop {
+            let state = self.step_traced(tracer)?;
+            if state != VmState::Running {
+                return Ok(state);
+            }
+        }
+    }
+
+    /// Runs with `io` attached, until the program halts or `io` has no input
+    /// to give it.
+    pub fn run_io(&mut self, io: &mut impl Io) -> Result<VmState, IntcodeError> {
+        loop {
+            match self.run()? {
+                VmState::NeedsInput => match io.input() {
+                    Some(value) => self.push_input(value),
+                    None => return Ok(VmState::NeedsInput),
+                },
+                VmState::Output(value) => io.output(value),
+                state => return Ok(state),
+            }
+        }
+    }
+
+    /// Runs to completion feeding `inputs` in order and returns everything
+    /// the program printed.
+    pub fn run_with_inputs(&mut self, inputs: &[i64]) -> Result<Vec<i64>, IntcodeError> {
+        self.inputs.extend(inputs);
+
+        let mut outputs = Vec::new();
+        loop {
+            match self.run()? {
+                VmState::Output(value) => outputs.push(value),
+                VmState::Halted => return Ok(outputs),
+                _ => return Err(IntcodeError::InputExhausted { pc: self.pc }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_and_immediate_modes() {
+        let mut vm = IntcodeVm::new(&[1002, 4, 3, 4, 33]);
+        assert_eq!(vm.run(), Ok(VmState::Halted));
+        assert_eq!(vm.memory().to_vec(), [1002, 4, 3, 4, 99]);
+    }
+
+    #[test]
+    fn comparisons_and_jumps() {
+        let program = [3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1];
+        assert_eq!(IntcodeVm::new(&program).run_with_inputs(&[0]), Ok(vec![0]));
+        assert_eq!(IntcodeVm::new(&program).run_with_inputs(&[7]), Ok(vec![1]));
+    }
+
+    #[test]
+    fn relative_mode_quine() {
+        let program = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        assert_eq!(
+            IntcodeVm::new(&program).run_with_inputs(&[]),
+            Ok(program.to_vec())
+        );
+    }
+
+    fn run_source(source: &str, inputs: &[i64]) -> Vec<i64> {
+        let program = crate::asm::assemble(source).unwrap();
+        IntcodeVm::new(&program).run_with_inputs(inputs).unwrap()
+    }
+
+    #[test]
+    fn arithmetic_in_every_mode() {
+        let source = "
+                ARB #100
+                ADD #2, #3, [x]
+                MUL [x], #4, [rb+1]
+                ADD [rb+1], [rb+1], [rb-1]
+                OUT [x]
+                OUT [rb+1]
+                OUT [99]
+                HLT
+            x:  DATA 0
+        ";
+        assert_eq!(run_source(source, &[]), [5, 20, 40]);
+    }
+
+    #[test]
+    fn input_and_output_modes() {
+        let source = "
+                IN [x]
+                IN [rb+20]
+                OUT [x]
+                OUT #-3
+                OUT [rb+20]
+                HLT
+            x:  DATA 0
+        ";
+        assert_eq!(run_source(source, &[7, 8]), [7, -3, 8]);
+    }
+
+    #[test]
+    fn comparisons() {
+        let source = "
+                IN [a]
+                LT [a], #10, [r]
+                OUT [r]
+                EQ #8, [a], [r]
+                OUT [r]
+                HLT
+            a:  DATA 0
+            r:  DATA 0
+        ";
+        assert_eq!(run_source(source, &[8]), [1, 1]);
+        assert_eq!(run_source(source, &[10]), [0, 0]);
+    }
+
+    #[test]
+    fn jumps() {
+        let source = "
+                IN [a]
+                JZ [a], #zero
+                JNZ #1, [target]
+            zero:
+                OUT #0
+                HLT
+            nonzero:
+                OUT #1
+                HLT
+            a:  DATA 0
+            target: DATA nonzero
+        ";
+        assert_eq!(run_source(source, &[0]), [0]);
+        assert_eq!(run_source(source, &[5]), [1]);
+    }
+
+    #[test]
+    fn waits_for_input_without_advancing() {
+        let mut vm = IntcodeVm::new(&[3, 0, 4, 0, 99]);
+        assert_eq!(vm.run(), Ok(VmState::NeedsInput));
+        assert_eq!(vm.pc(), 0);
+
+        vm.push_input(42);
+        assert_eq!(vm.run(), Ok(VmState::Output(42)));
+        assert_eq!(vm.run(), Ok(VmState::Halted));
+        assert_eq!(vm.run(), Ok(VmState::Halted));
+    }
+
+    #[test]
+    fn reports_errors() {
+        let run = |program: &[i64]| IntcodeVm::new(program).run_with_inputs(&[]);
+
+        assert_eq!(
+            run(&[1, 0, 0, 0, 42]),
+            Err(IntcodeError::InvalidOpcode { pc: 4, value: 42 })
+        );
+        assert_eq!(
+            run(&[301, 0, 0, 0]),
+            Err(IntcodeError::InvalidMode { pc: 0, value: 301 })
+        );
+        assert_eq!(
+            run(&[11101, 1, 1, 0, 99]),
+            Err(IntcodeError::WriteInImmediateMode { pc: 0 })
+        );
+        assert_eq!(
+            run(&[4, -1, 99]),
+            Err(IntcodeError::NegativeAddress { pc: 0, address: -1 })
+        );
+        assert_eq!(
+            run(&[3, 0, 99]),
+            Err(IntcodeError::InputExhausted { pc: 0 })
+        );
+        assert_eq!(
+            run(&[1102, i64::MAX, 2, 0, 99]),
+            Err(IntcodeError::Overflow { pc: 0 })
+        );
+        assert_eq!(
+            run(&[1101, 1, 1, 1 << 40, 99]),
+            Err(IntcodeError::AddressTooLarge {
+                pc: 0,
+                address: 1 << 40
+            })
+        );
+
+        let mut vm = IntcodeVm::new(&[1105, 1, 0]);
+        vm.set_step_limit(10);
+        assert_eq!(
+            vm.run(),
+            Err(IntcodeError::StepLimitExceeded { pc: 0, limit: 10 })
+        );
+    }
+
+    #[test]
+    fn snapshot_and_restore() {
+        // Adds up its inputs until it reads a 0.
+        let program = crate::asm::assemble(
+            "
+            loop:   IN [x]
+                    JZ [x], #done
+                    ADD [sum], [x], [sum]
+                    JNZ #1, #loop
+            done:   OUT [sum]
+                    HLT
+            x:      DATA 0
+            sum:    DATA 0
+            ",
+        )
+        .unwrap();
+
+        let mut vm = IntcodeVm::new(&program);
+        vm.push_input(5);
+        assert_eq!(vm.run(), Ok(VmState::NeedsInput));
+        let saved = vm.snapshot();
+
+        assert_eq!(vm.run_with_inputs(&[1, 0]), Ok(vec![6]));
+        vm.restore(&saved);
+        assert_eq!(vm.run_with_inputs(&[10, 0]), Ok(vec![15]));
+
+        let path =
+            std::env::temp_dir().join(format!("intcode-snapshot-{}.json", std::process::id()));
+        saved.save(&path).unwrap();
+        let loaded = Snapshot::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            IntcodeVm::from_snapshot(&loaded).run_with_inputs(&[2, 0]),
+            Ok(vec![7])
+        );
+    }
+
+    #[test]
+    fn channels_between_threads() {
+        use crate::io::ChannelIo;
+        use std::sync::mpsc::channel;
+        use std::thread;
+
+        // Doubles its input: in, out = in * 2.
+        let program = [3, 9, 1002, 9, 2, 9, 4, 9, 99, 0];
+        let (to_first, first_in) = channel();
+        let (first_out, second_in) = channel();
+        let (second_out, results) = channel();
+
+        let workers: Vec<_> = [(first_in, first_out), (second_in, second_out)]
+            .into_iter()
+            .map(|(receiver, sender)| {
+                thread::spawn(move || {
+                    IntcodeVm::new(&program).run_io(&mut ChannelIo { receiver, sender })
+                })
+            })
+            .collect();
+
+        to_first.send(5).unwrap();
+        assert_eq!(results.recv(), Ok(20));
+        for worker in workers {
+            assert_eq!(worker.join().unwrap(), Ok(VmState::Halted));
+        }
+    }
+}
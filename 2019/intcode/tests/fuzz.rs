@@ -0,0 +1,118 @@
+//! Random programs thrown at the VM. Programs are built mostly from valid
+//! instructions with operands near the program, so they get further than
+//! the first word, salted with arbitrary words and extreme values.
+
+use intcode::{disassemble, CompiledVm, IntcodeError, IntcodeVm, VmState};
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+
+const STEP_LIMIT: u64 = 10_000;
+
+fn word() -> impl Strategy<Value = i64> {
+    prop_oneof![
+        8 => 0..64i64,
+        2 => -8..0i64,
+        1 => any::<i64>(),
+        1 => select(vec![i64::MIN, i64::MAX, i64::MAX / 2, 1 << 40]),
+    ]
+}
+
+fn instruction() -> impl Strategy<Value = Vec<i64>> {
+    let opcodes = select(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 99]);
+    // Mode 3 doesn't exist; it's there to hit the error.
+    let modes = vec(select(vec![0, 0, 1, 1, 2, 2, 3]), 3);
+    (opcodes, modes, vec(word(), 3)).prop_map(|(opcode, modes, operands)| {
+        let count = match opcode {
+            1 | 2 | 7 | 8 => 3,
+            5 | 6 => 2,
+            99 => 0,
+            _ => 1,
+        };
+        let value = opcode + 100 * modes[0] + 1000 * modes[1] + 10000 * modes[2];
+        let mut words = vec![value];
+        words.extend(&operands[..count]);
+        words
+    })
+}
+
+/// An instruction the disassembler decodes: valid modes only, with operands
+/// leaning towards the extremes the formatter has to cope with.
+fn decodable_instruction() -> impl Strategy<Value = Vec<i64>> {
+    let opcodes = select(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 99]);
+    let modes = vec(select(vec![0, 1, 2]), 3);
+    let operand = prop_oneof![
+        2 => word(),
+        1 => select(vec![i64::MIN, i64::MIN + 1, -1, i64::MAX]),
+    ];
+    (opcodes, modes, vec(operand, 3)).prop_map(|(opcode, modes, operands)| {
+        let value = opcode + 100 * modes[0] + 1000 * modes[1] + 10000 * modes[2];
+        let mut words = vec![value];
+        words.extend(operands);
+        words
+    })
+}
+
+fn program() -> impl Strategy<Value = Vec<i64>> {
+    let piece = prop_oneof![
+        8 => instruction(),
+        1 => word().prop_map(|w| vec![w]),
+    ];
+    vec(piece, 1..40).prop_map(|pieces| pieces.concat())
+}
+
+/// Both backends, stepped side by side. Returns how the run ended.
+fn run_both(program: &[i64], inputs: &[i64]) -> Result<VmState, IntcodeError> {
+    let mut vm = IntcodeVm::new(program);
+    vm.set_step_limit(STEP_LIMIT);
+    let mut seed = vm.clone();
+    for &value in inputs {
+        seed.push_input(value);
+        vm.push_input(value);
+    }
+    let mut compiled = CompiledVm::from_vm(seed);
+
+    loop {
+        let expected = vm.step();
+        assert_eq!(compiled.step(), expected, "at step {}", vm.steps());
+        assert_eq!(compiled.vm().pc(), vm.pc());
+        assert_eq!(compiled.vm().relative_base(), vm.relative_base());
+        assert_eq!(compiled.vm().pending_inputs(), vm.pending_inputs());
+        if !matches!(expected, Ok(VmState::Running | VmState::Output(_))) {
+            assert_eq!(compiled.vm().memory().to_vec(), vm.memory().to_vec());
+            return expected;
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn vm_stops_within_budget(program in program(), inputs in vec(word(), 0..4)) {
+        let mut vm = IntcodeVm::new(&program);
+        vm.set_step_limit(STEP_LIMIT);
+        let result = vm.run_with_inputs(&inputs);
+
+        prop_assert!(vm.steps() <= STEP_LIMIT);
+        if let Err(e) = result {
+            prop_assert_eq!(e.pc(), vm.pc());
+        }
+    }
+
+    #[test]
+    fn backends_agree(program in program(), inputs in vec(word(), 0..4)) {
+        match run_both(&program, &inputs) {
+            Ok(state) => prop_assert!(state == VmState::Halted || state == VmState::NeedsInput),
+            Err(IntcodeError::StepLimitExceeded { limit, .. }) => prop_assert_eq!(limit, STEP_LIMIT),
+            Err(_) => {}
+        }
+    }
+
+    #[test]
+    fn disassembles_anything(
+        pieces in vec(prop_oneof![4 => decodable_instruction(), 1 => vec(word(), 1)], 0..24)
+    ) {
+        let disassembly = disassemble(&pieces.concat());
+        let _ = disassembly.to_string();
+    }
+}
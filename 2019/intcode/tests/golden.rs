@@ -0,0 +1,85 @@
+//! The diagnostic programs from 2019 days 5 and 9, on both backends. The
+//! examples from the puzzle text are always checked; the real programs only
+//! when the day's `Input.txt` is there, using the checks they carry
+//! themselves: day 5 prints zeros for every passing test before its
+//! diagnostic code, and day 9's BOOST prints a single keycode unless an
+//! opcode is broken.
+
+use intcode::{parse_program, CompiledVm, IntcodeVm};
+
+use std::fs;
+use std::path::Path;
+
+const COMPARE_TO_8: &str = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,\
+                            1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,\
+                            1105,1,46,98,99";
+const QUINE: &str = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+
+fn run(program: &[i64], inputs: &[i64]) -> Vec<i64> {
+    let outputs = IntcodeVm::new(program)
+        .run_with_inputs(inputs)
+        .unwrap_or_else(|e| panic!("{e}"));
+    assert_eq!(
+        CompiledVm::new(program).run_with_inputs(inputs),
+        Ok(outputs.clone())
+    );
+    outputs
+}
+
+fn real_input(day: &str) -> Option<Vec<i64>> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(day)
+        .join("Input.txt");
+    let input = fs::read_to_string(path).ok()?;
+    Some(parse_program(&input).unwrap())
+}
+
+#[test]
+fn day05_examples() {
+    let program = parse_program(COMPARE_TO_8).unwrap();
+    assert_eq!(run(&program, &[7]), [999]);
+    assert_eq!(run(&program, &[8]), [1000]);
+    assert_eq!(run(&program, &[9]), [1001]);
+
+    let program = parse_program("3,3,1105,-1,9,1101,0,0,12,4,12,99,1").unwrap();
+    assert_eq!(run(&program, &[0]), [0]);
+    assert_eq!(run(&program, &[5]), [1]);
+}
+
+#[test]
+fn day09_examples() {
+    let quine = parse_program(QUINE).unwrap();
+    assert_eq!(run(&quine, &[]), quine);
+
+    let program = parse_program("1102,34915192,34915192,7,4,7,99,0").unwrap();
+    assert_eq!(run(&program, &[])[0].to_string().len(), 16);
+
+    let program = parse_program("104,1125899906842624,99").unwrap();
+    assert_eq!(run(&program, &[]), [1125899906842624]);
+}
+
+#[test]
+fn day05_diagnostics() {
+    let Some(program) = real_input("day05") else {
+        return;
+    };
+
+    let outputs = run(&program, &[1]);
+    let (code, tests) = outputs.split_last().unwrap();
+    assert!(tests.iter().all(|&v| v == 0), "Failing tests: {tests:?}");
+    assert_ne!(*code, 0);
+
+    assert_eq!(run(&program, &[5]).len(), 1);
+}
+
+#[test]
+fn day09_boost() {
+    let Some(program) = real_input("day09") else {
+        return;
+    };
+
+    let outputs = run(&program, &[1]);
+    assert_eq!(outputs.len(), 1, "Broken opcodes: {outputs:?}");
+    assert_eq!(run(&program, &[2]).len(), 1);
+}
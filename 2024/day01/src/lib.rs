@@ -0,0 +1,91 @@
+use aoc_common::{Answer, Result, Solution};
+use std::cmp::Reverse;
+
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+
+pub type Heap = BinaryHeap<Reverse<i32>>;
+
+fn parse_input(s: &str) -> Result<(Heap, Heap)> {
+    let mut left_heap = BinaryHeap::new();
+    let mut right_heap = BinaryHeap::new();
+
+    for line in s.split("\n") {
+        let numbers: Vec<i32> = line
+            .split("   ")
+            .filter(|s| !s.is_empty())
+            .map(str::parse)
+            .collect::<std::result::Result<_, _>>()?;
+
+        let left_num = numbers.first();
+        let right_num = numbers.get(1);
+        if let Some(x) = left_num {
+            left_heap.push(Reverse(*x))
+        };
+
+        if let Some(x) = right_num {
+            right_heap.push(Reverse(*x))
+        };
+    }
+
+    Ok((left_heap, right_heap))
+}
+
+fn part1(mut left: Heap, mut right: Heap) -> i64 {
+    let mut sum: i64 = 0;
+
+    while !left.is_empty() {
+        let left_min = left.pop().unwrap_or(Reverse(0));
+        let right_min = right.pop().unwrap_or(Reverse(0));
+
+        sum += i64::from((left_min.0 - right_min.0).abs());
+    }
+
+    sum
+}
+
+fn part2(mut left: Heap, mut right: Heap) -> i64 {
+    let mut sum: i64 = 0;
+
+    let mut right_count: HashMap<i32, i32> = HashMap::new();
+    while !right.is_empty() {
+        let right_min = right.pop().unwrap_or(Reverse(0));
+        let current = right_count.get(&right_min.0);
+
+        match current {
+            Some(x) => right_count.insert(right_min.0, x + 1),
+            None => right_count.insert(right_min.0, 1),
+        };
+    }
+
+    while !left.is_empty() {
+        let left_min = left.pop().unwrap_or(Reverse(0));
+        let count = right_count.get(&left_min.0);
+        let multiplier = match count {
+            Some(x) => *x,
+            None => 0,
+        };
+
+        sum += i64::from(left_min.0 * multiplier);
+    }
+
+    sum
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Heap, Heap);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((left, right): &Self::Input) -> Result<Answer> {
+        Ok(part1(left.clone(), right.clone()).into())
+    }
+
+    fn part2((left, right): &Self::Input) -> Result<Answer> {
+        Ok(part2(left.clone(), right.clone()).into())
+    }
+}
@@ -1,37 +1,21 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
-
-fn parse_input(file_name: &str) -> Vec<Vec<i32>> {
-    let path = Path::new(file_name);
-    let display = path.display();
-
-    let mut file = match File::open(&path) {
-        Err(err) => panic!("Couldn't open {}: {}", display, err),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-    match file.read_to_string(&mut s) {
-        Err(err) => panic!("Couldn't read {}: {}", display, err),
-        Ok(_) => (),
-    }
+use aoc_common::{Answer, Result, Solution};
 
+fn parse_input(s: &str) -> Result<Vec<Vec<i32>>> {
     let mut results: Vec<Vec<i32>> = Vec::new();
 
     for line in s.split("\n") {
         let numbers: Vec<i32> = line
             .split(" ")
             .filter(|s| !s.is_empty())
-            .map(|s| s.parse().unwrap())
-            .collect();
+            .map(str::parse)
+            .collect::<std::result::Result<_, _>>()?;
 
-        if numbers.len() > 0 {
+        if !numbers.is_empty() {
             results.push(numbers);
         }
     }
 
-    return results;
+    Ok(results)
 }
 
 fn parse_report(report: Vec<i32>, has_removed_value: bool) -> bool {
@@ -42,11 +26,10 @@ fn parse_report(report: Vec<i32>, has_removed_value: bool) -> bool {
     let parse_others = |i: usize| -> bool {
         let mut copy = report.clone();
         copy.remove(i);
-        return parse_report(copy, true);
+        parse_report(copy, true)
     };
 
-    for level_index in 1..report.len() {
-        let value = report[level_index];
+    for (level_index, &value) in report.iter().enumerate().skip(1) {
         let diff = value - previous;
         match is_increasing {
             true => {
@@ -65,7 +48,7 @@ fn parse_report(report: Vec<i32>, has_removed_value: bool) -> bool {
                 }
             }
             false => {
-                if diff >= 0 || diff < -3 {
+                if !(-3..0).contains(&diff) {
                     if has_removed_value {
                         is_valid = false;
                         break;
@@ -83,7 +66,7 @@ fn parse_report(report: Vec<i32>, has_removed_value: bool) -> bool {
         previous = value;
     }
 
-    return is_valid;
+    is_valid
 }
 
 fn part1(input: Vec<Vec<i32>>) -> u64 {
@@ -94,7 +77,7 @@ fn part1(input: Vec<Vec<i32>>) -> u64 {
         }
     }
 
-    return sum;
+    sum
 }
 
 fn part2(input: Vec<Vec<i32>>) -> u64 {
@@ -105,15 +88,23 @@ fn part2(input: Vec<Vec<i32>>) -> u64 {
         }
     }
 
-    return sum;
+    sum
 }
 
-fn main() {
-    // let input = parse_input("Example.txt");
-    let input = parse_input("Input.txt");
+pub struct Day02;
 
-    // println!("{:?}", input);
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
 
-    println!("Part 1: {}", part1(input.clone()));
-    println!("Part 2: {}", part2(input.clone()));
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input.clone()).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input.clone()).into())
+    }
 }
@@ -1,23 +1,7 @@
+use aoc_common::{Answer, Result, Solution};
 use regex::Regex;
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
-
-fn parse_input(file_name: &str) -> Vec<String> {
-    let path = Path::new(file_name);
-    let display = path.display();
-
-    let mut file = match File::open(&path) {
-        Err(err) => panic!("Couldn't open {}: {}", display, err),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-    match file.read_to_string(&mut s) {
-        Err(err) => panic!("Couldn't read {}: {}", display, err),
-        Ok(_) => (),
-    }
 
+fn parse_input(s: &str) -> Result<Vec<String>> {
     let mut input = String::new();
     let re = Regex::new(r"(mul\(\d+,\d+\))|(do\(\))|(don't\(\))").unwrap();
 
@@ -31,16 +15,16 @@ fn parse_input(file_name: &str) -> Vec<String> {
         inputs.push(String::from(value));
     }
 
-    return inputs;
+    Ok(inputs)
 }
 
-fn parse_inputs(inputs: &Vec<String>, should_disable: bool) -> i64 {
+fn parse_inputs(inputs: &[String], should_disable: bool) -> i64 {
     let mut sum: i64 = 0;
     let mut enabled = true;
     let re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
     for input in inputs {
-        if input.chars().nth(0).unwrap() == 'm' {
-            for (_, [v1, v2]) in re.captures_iter(&input).map(|s| s.extract()) {
+        if input.starts_with('m') {
+            for (_, [v1, v2]) in re.captures_iter(input).map(|s| s.extract()) {
                 // println!(
                 //     "mul {}, {}",
                 //     v1.parse::<i64>().unwrap(),
@@ -63,22 +47,31 @@ fn parse_inputs(inputs: &Vec<String>, should_disable: bool) -> i64 {
             }
         }
     }
-    return sum;
+    sum
 }
 
-fn part1(inputs: &Vec<String>) -> i64 {
+fn part1(inputs: &[String]) -> i64 {
     parse_inputs(inputs, false)
 }
 
-fn part2(inputs: &Vec<String>) -> i64 {
+fn part2(inputs: &[String]) -> i64 {
     parse_inputs(inputs, true)
 }
 
-fn main() {
-    // let inputs = parse_input("Example2.txt");
-    let inputs = parse_input("Input.txt");
-    println!("Part 1: {}", part1(&inputs));
-    println!("Part 2: {}", part2(&inputs));
-    //
-    // println!("Hello, world!");
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
 }
@@ -0,0 +1,83 @@
+use aoc_common::{Answer, Grid, Result, Solution, Vec2};
+
+fn parse_input(s: &str) -> Result<Grid<char>> {
+    Grid::parse(s)
+}
+
+fn check_input(grid: &Grid<char>, pos: Vec2, check_char: char, dir: Option<Vec2>) -> u64 {
+    if grid.get(&pos) != Some(&check_char) {
+        return 0;
+    }
+
+    if check_char == 'S' {
+        return 1;
+    }
+
+    let next_char = match check_char {
+        'X' => 'M',
+        'M' => 'A',
+        'A' => 'S',
+        _ => ' ',
+    };
+
+    match dir {
+        None => grid
+            .neighbors8(&pos)
+            .map(|next| check_input(grid, next, next_char, Some(next - pos)))
+            .sum(),
+        Some(dir) => check_input(grid, pos + dir, next_char, Some(dir)),
+    }
+}
+
+fn part1(grid: &Grid<char>) -> u64 {
+    grid.positions()
+        .map(|pos| check_input(grid, pos, 'X', None))
+        .sum()
+}
+
+fn part2(grid: &Grid<char>) -> u64 {
+    let mut sum: u64 = 0;
+
+    let get_index = |x: i32, y: i32| -> char { grid[Vec2 { x, y }] };
+
+    for y in 1..(grid.height() - 1) {
+        for x in 1..(grid.width() - 1) {
+            if get_index(x, y) != 'A' {
+                continue;
+            }
+
+            let top_left = get_index(x - 1, y - 1);
+            let top_right = get_index(x + 1, y - 1);
+            let bottom_left = get_index(x - 1, y + 1);
+            let bottom_right = get_index(x + 1, y + 1);
+
+            if ((top_left == 'M' && bottom_right == 'S')
+                || (top_left == 'S' && bottom_right == 'M'))
+                && ((top_right == 'M' && bottom_left == 'S')
+                    || (top_right == 'S' && bottom_left == 'M'))
+            {
+                sum += 1;
+            }
+        }
+    }
+
+    sum
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
@@ -1,38 +1,26 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
+use aoc_common::{Answer, Error, Result, Solution};
 
 use multimap::MultiMap;
 
-fn parse_input(file_name: &str) -> (MultiMap<u32, u32>, Vec<Vec<u32>>) {
-    let path = Path::new(file_name);
-    let display = path.display();
-
-    let mut file = match File::open(path) {
-        Err(err) => panic!("Couldn't open {}: {}", display, err),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-
-    if !file.read_to_string(&mut s).is_ok() {
-        panic!("Couldn't read {}", file_name);
-    }
+pub type Rules = MultiMap<u32, u32>;
 
+fn parse_input(s: &str) -> Result<(Rules, Vec<Vec<u32>>)> {
     let mut rules = MultiMap::new();
     let mut pages: Vec<Vec<u32>> = Vec::new();
 
     for line in s.split("\n") {
         if line.len() == 5 {
             // Rule
-            let values: Vec<u32> = line.split("|").map(|s| s.parse().unwrap()).collect();
-            rules.insert(values[0], values[1]);
+            let (before, after) = line
+                .split_once('|')
+                .ok_or_else(|| Error::parse(format!("Invalid rule {}", line)))?;
+            rules.insert(before.parse()?, after.parse()?);
         } else {
             let numbers: Vec<u32> = line
                 .split(",")
                 .filter(|s| !s.is_empty())
-                .map(|s| s.parse().unwrap())
-                .collect();
+                .map(str::parse)
+                .collect::<std::result::Result<_, _>>()?;
 
             if !numbers.is_empty() {
                 pages.push(numbers);
@@ -40,14 +28,10 @@ fn parse_input(file_name: &str) -> (MultiMap<u32, u32>, Vec<Vec<u32>>) {
         }
     }
 
-    (rules, pages)
+    Ok((rules, pages))
 }
 
-fn is_valid(
-    input: &Vec<u32>,
-    rules: &MultiMap<u32, u32>,
-    fix: &mut Option<Vec<(usize, usize)>>,
-) -> bool {
+fn is_valid(input: &[u32], rules: &Rules, fix: &mut Option<Vec<(usize, usize)>>) -> bool {
     let mut valid = true;
     for i in 1..input.len() {
         let current_value = input[i];
@@ -58,9 +42,7 @@ fn is_valid(
             continue;
         }
 
-        for j in 0..i {
-            let check_value = input[j];
-
+        for (j, &check_value) in input.iter().enumerate().take(i) {
             for v in bound_rules {
                 if check_value == *v {
                     if let Some(values) = fix {
@@ -74,14 +56,14 @@ fn is_valid(
         }
     }
 
-    return valid;
+    valid
 }
 
-fn part1(rules: &MultiMap<u32, u32>, pages: &Vec<Vec<u32>>) -> u32 {
+fn part1(rules: &Rules, pages: &[Vec<u32>]) -> u32 {
     let mut sum: u32 = 0;
     for page in pages {
         let mut none_option = None;
-        if is_valid(&page, rules, &mut none_option) {
+        if is_valid(page, rules, &mut none_option) {
             let mid = (page.len() - 1) / 2;
             sum += page[mid];
         }
@@ -90,19 +72,18 @@ fn part1(rules: &MultiMap<u32, u32>, pages: &Vec<Vec<u32>>) -> u32 {
     sum
 }
 
-fn part2(rules: &MultiMap<u32, u32>, pages: &Vec<Vec<u32>>) -> u32 {
+fn part2(rules: &Rules, pages: &[Vec<u32>]) -> u32 {
     let mut sum: u32 = 0;
     for page in pages {
         let mut fix: Option<Vec<(usize, usize)>> = Some(Vec::new());
-        if !is_valid(&page, rules, &mut fix) {
+        if !is_valid(page, rules, &mut fix) {
             let mut page_entry = page.clone();
 
             let first_fix = fix.as_ref().unwrap()[0];
             let (i1, i2) = first_fix;
 
-            let t = page_entry[i1];
-            page_entry[i1] = page_entry[i2];
-            page_entry[i2] = t;
+            let _t = page_entry[i1];
+            page_entry.swap(i1, i2);
 
             fix = Some(Vec::new());
 
@@ -110,9 +91,7 @@ fn part2(rules: &MultiMap<u32, u32>, pages: &Vec<Vec<u32>>) -> u32 {
                 let first_fix = fix.as_ref().unwrap()[0];
                 let (i1, i2) = first_fix;
 
-                let t = page_entry[i1];
-                page_entry[i1] = page_entry[i2];
-                page_entry[i2] = t;
+                page_entry.swap(i1, i2);
 
                 fix = Some(Vec::new());
             }
@@ -125,13 +104,20 @@ fn part2(rules: &MultiMap<u32, u32>, pages: &Vec<Vec<u32>>) -> u32 {
     sum
 }
 
-fn main() {
-    // let (rules, pages) = parse_input("Example.txt");
-    let (rules, pages) = parse_input("Input.txt");
+pub struct Day05;
 
-    // println!("{:?}", input);
-    // let input = parse_input("Input.txt");
+impl Solution for Day05 {
+    type Input = (Rules, Vec<Vec<u32>>);
 
-    println!("Part 1: {}", part1(&rules, &pages));
-    println!("Part 2: {}", part2(&rules, &pages));
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((rules, pages): &Self::Input) -> Result<Answer> {
+        Ok(part1(rules, pages).into())
+    }
+
+    fn part2((rules, pages): &Self::Input) -> Result<Answer> {
+        Ok(part2(rules, pages).into())
+    }
 }
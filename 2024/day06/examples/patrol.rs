@@ -0,0 +1,43 @@
+//! Animates the guard's patrol, leaving a trail over the tiles already
+//! walked. Takes the input file and an optional delay between frames in
+//! milliseconds.
+use aoc2024_day06::{trace_path, Day06};
+use aoc_common::render::{Animation, Color, Render, Style};
+use aoc_common::{read_input, Solution};
+
+use std::env;
+use std::process;
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 2 || args.len() > 3 {
+        eprintln!("Usage: patrol INPUT [DELAY_MS]");
+        process::exit(2);
+    }
+    let delay = match args.get(2) {
+        Some(ms) => ms.parse()?,
+        None => 50,
+    };
+
+    let (start, grid) = Day06::parse(&read_input(&args[1])?)?;
+    let mut steps = vec![start];
+    trace_path(&start, &grid, true, |guard| steps.push(*guard));
+
+    let mut animation = Animation::stdout(Duration::from_millis(delay));
+    for (i, guard) in steps.iter().enumerate() {
+        let trail = steps[..i].iter().map(|g| g.pos);
+        let frame = Render::new(&grid, |c| match c {
+            '#' => Style::glyph('#').with_fg(Color::Gray),
+            _ => Style::glyph('.').with_fg(Color::Gray),
+        })
+        .overlay(trail, Style::bg(Color::Blue))
+        .overlay(
+            [guard.pos],
+            Style::glyph(guard.dir.arrow()).with_fg(Color::Yellow),
+        );
+        animation.frame(format!("{frame}step {i}\n"))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use aoc_common::{Answer, Direction, Grid, Result, Solution, Vec2};
+
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub pos: Vec2,
+    pub dir: Direction,
+}
+
+fn parse_input(s: &str) -> Result<(Guard, Grid<char>)> {
+    let grid = Grid::parse(s)?;
+    let start = Guard {
+        pos: grid.find('^').unwrap_or(Vec2 { x: -1, y: -1 }),
+        dir: Direction::Up,
+    };
+
+    Ok((start, grid))
+}
+
+/// Walks the guard off the map, calling `on_step` with every move or turn.
+/// When tracing the path, stops early and returns `false` on a loop.
+pub fn trace_path(
+    start: &Guard,
+    grid: &Grid<char>,
+    trace_path: bool,
+    mut on_step: impl FnMut(&Guard),
+) -> (bool, HashSet<Guard>) {
+    let mut visited: HashSet<Guard> = HashSet::new();
+
+    let mut current = *start;
+
+    if !trace_path {
+        visited.insert(*start);
+    }
+
+    loop {
+        let new_pos = current.pos + current.dir;
+
+        let Some(next_char) = grid.get(&new_pos) else {
+            break;
+        };
+        let (write_value, next) = match next_char {
+            '.' | '^' => (
+                true,
+                Guard {
+                    pos: new_pos,
+                    dir: current.dir,
+                },
+            ),
+            '#' => (
+                false,
+                Guard {
+                    pos: current.pos,
+                    dir: current.dir.turn_right(),
+                },
+            ),
+            _ => panic!("Unexepected Character"),
+        };
+
+        if trace_path && write_value {
+            let write_pos = Guard {
+                pos: current.pos,
+                dir: next.dir,
+            };
+            if visited.contains(&write_pos) {
+                return (false, visited);
+            } else {
+                visited.insert(write_pos);
+            }
+        } else if !trace_path {
+            visited.insert(Guard {
+                pos: next.pos,
+                dir: Direction::Up,
+            });
+        }
+
+        on_step(&next);
+        current = next;
+    }
+
+    (true, visited)
+}
+
+fn part1(start: &Guard, grid: &Grid<char>) -> u64 {
+    let (_, path) = trace_path(start, grid, false, |_| {});
+    path.len() as u64
+}
+
+fn part2(start: &Guard, grid: &Grid<char>) -> u64 {
+    let (_, path) = trace_path(start, grid, true, |_| {});
+
+    let mut mut_grid = grid.clone();
+
+    let mut valid_nodes: HashSet<Vec2> = HashSet::new();
+
+    for guard in path {
+        let new_pos = guard.pos + guard.dir;
+
+        if grid.get(&new_pos) != Some(&'.') {
+            continue;
+        }
+
+        mut_grid[new_pos] = '#';
+        let (valid_path, _) = trace_path(start, &mut_grid, true, |_| {});
+        if !valid_path {
+            valid_nodes.insert(new_pos);
+        }
+        mut_grid[new_pos] = '.';
+    }
+
+    valid_nodes.len() as u64
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = (Guard, Grid<char>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((start, grid): &Self::Input) -> Result<Answer> {
+        Ok(part1(start, grid).into())
+    }
+
+    fn part2((start, grid): &Self::Input) -> Result<Answer> {
+        Ok(part2(start, grid).into())
+    }
+}
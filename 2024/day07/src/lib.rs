@@ -1,24 +1,8 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
+use aoc_common::{Answer, Error, Result, Solution};
 
-type Input = Vec<(i64, Vec<i64>)>;
-
-fn parse_input(file_name: &str) -> Input {
-    let path = Path::new(file_name);
-    let display = path.display();
-
-    let mut file = match File::open(path) {
-        Err(err) => panic!("Couldn't open {}: {}", display, err),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-
-    if file.read_to_string(&mut s).is_err() {
-        panic!("Couldn't read {}", file_name);
-    }
+pub type Input = Vec<(i64, Vec<i64>)>;
 
+fn parse_input(s: &str) -> Result<Input> {
     let mut input: Input = Vec::new();
 
     for line in s.split("\n") {
@@ -26,19 +10,21 @@ fn parse_input(file_name: &str) -> Input {
             continue;
         }
 
-        let split: Vec<&str> = line.split(":").collect();
-        let target: i64 = split[0].parse().unwrap();
+        let (target, values) = line
+            .split_once(':')
+            .ok_or_else(|| Error::parse(format!("Missing ':' in {}", line)))?;
+        let target: i64 = target.parse()?;
 
-        let values: Vec<i64> = split[1]
+        let values: Vec<i64> = values
             .split(" ")
             .filter(|s| !s.is_empty())
-            .map(|s| s.parse().unwrap())
-            .collect();
+            .map(str::parse)
+            .collect::<std::result::Result<_, _>>()?;
 
         input.push((target, values));
     }
 
-    input
+    Ok(input)
 }
 
 fn matching_digits(to_match: &i64, compare: &i64) -> Option<i64> {
@@ -127,13 +113,20 @@ fn part2(input: &Input) -> i64 {
     sum
 }
 
-fn main() {
-    // let input = parse_input("Example.txt");
-    let input = parse_input("Input.txt");
+pub struct Day07;
 
-    let p1 = part1(&input);
-    let p2 = part2(&input);
+impl Solution for Day07 {
+    type Input = Input;
 
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
 }
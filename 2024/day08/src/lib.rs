@@ -0,0 +1,61 @@
+use aoc_common::{Answer, Grid, Result, Solution, Vec2};
+
+use multimap::MultiMap;
+
+use std::collections::HashSet;
+
+fn parse_input(s: &str) -> Result<Grid<char>> {
+    Grid::parse(s)
+}
+
+fn puzzle(grid: &Grid<char>, min_multiplier: i32, max_multiplier: i32) -> u64 {
+    let frequencies: MultiMap<char, Vec2> = grid
+        .iter()
+        .filter(|(_, c)| **c != '.')
+        .map(|(pos, c)| (*c, pos))
+        .collect();
+    let mut antinodes: HashSet<Vec2> = HashSet::new();
+
+    for values in frequencies.iter_all().map(|(_, values)| values) {
+        for f1 in values.iter() {
+            for f2 in values.iter() {
+                if f1 == f2 {
+                    continue;
+                }
+
+                for mul in min_multiplier..=max_multiplier {
+                    let new_pos = Vec2 {
+                        x: f1.x + mul * (f2.x - f1.x),
+                        y: f1.y + mul * (f2.y - f1.y),
+                    };
+
+                    if !grid.in_bounds(&new_pos) {
+                        continue;
+                    }
+
+                    antinodes.insert(new_pos);
+                }
+            }
+        }
+    }
+
+    antinodes.len() as u64
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        Ok(puzzle(grid, 2, 2).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        Ok(puzzle(grid, 1, 100).into())
+    }
+}
@@ -1,32 +1,16 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
+use aoc_common::{Answer, Error, Result, Solution};
 
 use std::cmp;
 
 #[derive(Debug, Clone)]
-struct FileEntry {
+pub struct FileEntry {
     id: u32,
     size: u32,
     is_entry: bool,
     offset: u32,
 }
 
-fn parse_input(file_name: &str) -> Vec<FileEntry> {
-    let path = Path::new(file_name);
-    let display = path.display();
-
-    let mut file = match File::open(path) {
-        Err(err) => panic!("Couldn't open {}: {}", display, err),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-
-    if file.read_to_string(&mut s).is_err() {
-        panic!("Couldn't read {}", file_name);
-    }
-
+fn parse_input(s: &str) -> Result<Vec<FileEntry>> {
     let mut file_entries: Vec<FileEntry> = Vec::new();
 
     let mut id: u32 = 0;
@@ -37,7 +21,9 @@ fn parse_input(file_name: &str) -> Vec<FileEntry> {
         }
 
         for char in line.chars() {
-            let value: u32 = char.to_digit(10).unwrap();
+            let value: u32 = char
+                .to_digit(10)
+                .ok_or_else(|| Error::parse(format!("Invalid size {:?}", char)))?;
             let entry = if is_entry {
                 id += 1;
                 FileEntry {
@@ -61,10 +47,14 @@ fn parse_input(file_name: &str) -> Vec<FileEntry> {
         }
     }
 
-    file_entries
+    if file_entries.is_empty() {
+        return Err(Error::parse("Empty disk map"));
+    }
+
+    Ok(file_entries)
 }
 
-fn part1(file_entries: &mut Vec<FileEntry>) -> u64 {
+fn part1(file_entries: &mut [FileEntry]) -> u64 {
     let mut first_ptr: usize = 0;
     let mut last_ptr: usize = file_entries.len() - 1;
 
@@ -114,7 +104,7 @@ fn part1(file_entries: &mut Vec<FileEntry>) -> u64 {
     sum
 }
 
-fn part2(file_entries: &mut Vec<FileEntry>) -> u64 {
+fn part2(file_entries: &mut [FileEntry]) -> u64 {
     let mut current_offset = 0;
     for entry in file_entries.iter_mut() {
         entry.offset = current_offset;
@@ -167,18 +157,20 @@ fn part2(file_entries: &mut Vec<FileEntry>) -> u64 {
     sum
 }
 
-fn main() {
-    // let mut parsed = parse_input("SmallExample.txt");
-    // let mut parsed = parse_input("Example.txt");
-    let mut parsed = parse_input("Input.txt");
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<FileEntry>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
 
-    let mut copy = parsed.clone();
-    let p1 = part1(&mut copy);
-    let p2 = part2(&mut parsed);
-    // let parsed = parse_input("Example.txt");
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(&mut input.clone()).into())
+    }
 
-    // println!("{:?}", parsed);
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
-    // println!("Hello, world!");
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(&mut input.clone()).into())
+    }
 }
@@ -0,0 +1,113 @@
+use aoc_common::{Answer, Error, Grid, Result, Solution, Vec2};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+fn parse_input(s: &str) -> Result<Grid<u8>> {
+    Grid::parse_with(s, |c| {
+        c.to_digit(10)
+            .map(|d| d as u8)
+            .ok_or_else(|| Error::parse(format!("Unexpected height {c:?}")))
+    })
+}
+
+fn part1(grid: &Grid<u8>) -> u64 {
+    let mut positions: Vec<HashMap<Vec2, HashSet<Vec2>>> = Vec::new();
+    positions.resize(10, HashMap::new());
+
+    for (pos, value) in grid.iter() {
+        if *value == 9_u8 {
+            positions
+                .get_mut(9)
+                .unwrap()
+                .insert(pos, HashSet::from([pos]));
+        }
+    }
+
+    for current_level in (1..=9).rev() {
+        let clone = positions.clone();
+        let current_position = clone.get(current_level).unwrap().clone();
+
+        for (pos, score) in current_position {
+            for new_pos in grid.neighbors4(&pos) {
+                if grid[new_pos] == (current_level - 1) as u8 {
+                    let next_level = positions.get_mut(current_level - 1).unwrap();
+
+                    if next_level.get(&new_pos).is_some() {
+                        let mut new_union = HashSet::new();
+                        for u in next_level.get(&new_pos).unwrap().union(&score) {
+                            new_union.insert(*u);
+                        }
+
+                        next_level.insert(new_pos, new_union);
+                    } else {
+                        next_level.insert(new_pos, score.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    let mut sum: u64 = 0;
+    for score in positions[0].values() {
+        sum += score.len() as u64;
+    }
+
+    sum
+}
+
+fn part2(grid: &Grid<u8>) -> u64 {
+    let mut positions: Vec<HashMap<Vec2, u64>> = Vec::new();
+    positions.resize(10, HashMap::new());
+
+    for (pos, value) in grid.iter() {
+        if *value == 9_u8 {
+            positions.get_mut(9).unwrap().insert(pos, 1);
+        }
+    }
+
+    for current_level in (1..=9).rev() {
+        let clone = positions.clone();
+        let current_position = clone.get(current_level).unwrap().clone();
+
+        for (pos, score) in current_position {
+            for new_pos in grid.neighbors4(&pos) {
+                if grid[new_pos] == (current_level - 1) as u8 {
+                    let next_level = positions.get_mut(current_level - 1).unwrap();
+
+                    if next_level.get(&new_pos).is_some() {
+                        let previous_score = next_level.get(&new_pos).unwrap();
+
+                        next_level.insert(new_pos, previous_score + score);
+                    } else {
+                        next_level.insert(new_pos, score);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut sum: u64 = 0;
+    for score in positions[0].values() {
+        sum += *score;
+    }
+
+    sum
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        Ok(part1(grid).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        Ok(part2(grid).into())
+    }
+}
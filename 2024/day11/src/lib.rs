@@ -1,24 +1,8 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
+use aoc_common::{Answer, Result, Solution};
 
 use std::collections::HashMap;
 
-fn parse_input(file_name: &str) -> Vec<u64> {
-    let path = Path::new(file_name);
-    let display = path.display();
-
-    let mut file = match File::open(path) {
-        Err(err) => panic!("Couldn't open {}: {}", display, err),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-
-    if file.read_to_string(&mut s).is_err() {
-        panic!("Couldn't read {}", file_name);
-    }
-
+fn parse_input(s: &str) -> Result<Vec<u64>> {
     let mut values: Vec<u64> = Vec::new();
 
     for line in s.split("\n") {
@@ -29,13 +13,13 @@ fn parse_input(file_name: &str) -> Vec<u64> {
         let mut line_values: Vec<u64> = line
             .split(" ")
             .filter(|s| !s.is_empty())
-            .map(|s| s.parse().unwrap())
-            .collect();
+            .map(str::parse)
+            .collect::<std::result::Result<_, _>>()?;
 
         values.append(&mut line_values);
     }
 
-    values
+    Ok(values)
 }
 
 fn digit_count(value: u64) -> u64 {
@@ -51,7 +35,7 @@ fn digit_count(value: u64) -> u64 {
 
 fn split_digit(value: u64) -> (u64, u64) {
     let count = digit_count(value);
-    assert!(count % 2 == 0);
+    assert!(count.is_multiple_of(2));
 
     let mask = u64::pow(10, (count / 2) as u32);
 
@@ -81,7 +65,7 @@ fn calculate_value(
         return score;
     }
 
-    if digit_count(value) % 2 == 0 {
+    if digit_count(value).is_multiple_of(2) {
         let (left, right) = split_digit(value);
 
         let score_left = calculate_value(left, blinks + 1, memory, max_blinks);
@@ -99,7 +83,7 @@ fn calculate_value(
     score
 }
 
-fn puzzle(values: &Vec<u64>, part1: bool) -> u64 {
+fn puzzle(values: &[u64], part1: bool) -> u64 {
     let mut sum: u64 = 0;
 
     let mut memory: HashMap<(u64, u8), u64> = HashMap::new();
@@ -111,13 +95,20 @@ fn puzzle(values: &Vec<u64>, part1: bool) -> u64 {
     sum
 }
 
-fn main() {
-    // let parsed = parse_input("Example.txt");
-    let parsed = parse_input("Input.txt");
+pub struct Day11;
 
-    let p1 = puzzle(&parsed, true);
-    let p2 = puzzle(&parsed, false);
+impl Solution for Day11 {
+    type Input = Vec<u64>;
 
-    println!("Part 1: {p1}");
-    println!("Part 2: {p2}");
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(puzzle(input, true).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(puzzle(input, false).into())
+    }
 }
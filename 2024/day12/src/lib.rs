@@ -0,0 +1,178 @@
+use aoc_common::{Answer, Grid, Result, Solution, Vec2};
+use std::collections::HashSet;
+
+fn parse_input(s: &str) -> Result<Grid<char>> {
+    Grid::parse(s)
+}
+
+fn parse_regions(grid: &Grid<char>) -> Vec<HashSet<Vec2>> {
+    let mut all_checked: HashSet<Vec2> = HashSet::new();
+    let mut regions: Vec<HashSet<Vec2>> = Vec::new();
+
+    for (pos, value) in grid.iter() {
+        if all_checked.contains(&pos) {
+            continue;
+        }
+
+        let mut checked: HashSet<Vec2> = HashSet::new();
+
+        let mut to_check: Vec<Vec2> = Vec::new();
+
+        checked.insert(pos);
+        to_check.push(pos);
+
+        while let Some(pos) = to_check.pop() {
+            for next in grid.neighbors4(&pos) {
+                if grid[next] == *value && !checked.contains(&next) {
+                    checked.insert(next);
+                    to_check.push(next);
+                    all_checked.insert(next);
+                }
+            }
+        }
+
+        regions.push(checked);
+    }
+
+    regions
+}
+
+fn part1(regions: &[HashSet<Vec2>]) -> u64 {
+    let mut sum: u64 = 0;
+    for region in regions.iter() {
+        let area = region.len();
+        let mut perimeter: u64 = 0;
+        for p in region {
+            let left = Vec2 { x: p.x - 1, y: p.y };
+            let right = Vec2 { x: p.x + 1, y: p.y };
+            let up = Vec2 { x: p.x, y: p.y - 1 };
+            let down = Vec2 { x: p.x, y: p.y + 1 };
+
+            if !region.contains(&left) {
+                perimeter += 1;
+            }
+            if !region.contains(&right) {
+                perimeter += 1;
+            }
+            if !region.contains(&down) {
+                perimeter += 1;
+            }
+            if !region.contains(&up) {
+                perimeter += 1;
+            }
+        }
+
+        sum += (area as u64) * perimeter;
+    }
+
+    sum
+}
+
+fn part2(regions: &[HashSet<Vec2>]) -> u64 {
+    let mut sum: u64 = 0;
+    for region in regions.iter() {
+        let area = region.len();
+        let mut sides: u64 = 0;
+
+        let mut directions: Vec<HashSet<Vec2>> = Vec::new();
+        directions.resize(4, HashSet::new());
+
+        // println!("Checking Region: {:?}", region);
+
+        for p in region {
+            let left = Vec2 { x: p.x - 1, y: p.y };
+            let right = Vec2 { x: p.x + 1, y: p.y };
+            let up = Vec2 { x: p.x, y: p.y - 1 };
+            let down = Vec2 { x: p.x, y: p.y + 1 };
+
+            if !region.contains(&left) {
+                directions.get_mut(0).unwrap().insert(left);
+            }
+            if !region.contains(&right) {
+                directions.get_mut(2).unwrap().insert(right);
+            }
+            if !region.contains(&down) {
+                directions.get_mut(3).unwrap().insert(down);
+            }
+            if !region.contains(&up) {
+                directions.get_mut(1).unwrap().insert(up);
+            }
+        }
+        // println!("Directions: {:?}", directions);
+
+        for dir in directions.iter() {
+            if dir.is_empty() {
+                continue;
+            }
+            // println!("\tDir: {:?}", dir);
+
+            let mut all_ready_checked: HashSet<Vec2> = HashSet::new();
+            let mut faces: Vec<HashSet<Vec2>> = Vec::new();
+            for p in dir {
+                let mut to_check: Vec<Vec2> = Vec::new();
+                let mut face: HashSet<Vec2> = HashSet::new();
+
+                if all_ready_checked.contains(p) {
+                    continue;
+                }
+
+                face.insert(*p);
+                to_check.push(*p);
+
+                let mut add_element =
+                    |pos: &Vec2,
+                     all_ready_checked: &mut HashSet<Vec2>,
+                     to_check: &mut Vec<Vec2>| {
+                        if dir.contains(pos) && !all_ready_checked.contains(pos) {
+                            all_ready_checked.insert(*pos);
+                            face.insert(*pos);
+                            to_check.push(*pos);
+                        }
+                    };
+
+                while let Some(p) = to_check.pop() {
+                    let left = Vec2 { x: p.x - 1, y: p.y };
+                    let right = Vec2 { x: p.x + 1, y: p.y };
+                    let up = Vec2 { x: p.x, y: p.y - 1 };
+                    let down = Vec2 { x: p.x, y: p.y + 1 };
+
+                    add_element(&left, &mut all_ready_checked, &mut to_check);
+                    add_element(&right, &mut all_ready_checked, &mut to_check);
+                    add_element(&up, &mut all_ready_checked, &mut to_check);
+                    add_element(&down, &mut all_ready_checked, &mut to_check);
+                }
+                // println!("\t\tFace: {:?}", face);
+
+                if !face.is_empty() {
+                    faces.push(face);
+                }
+            }
+            // println!("\tFaces: {:?}", faces);
+
+            sides += faces.len() as u64;
+        }
+        // println!("Sides: {}", sides);
+
+        sum += (area as u64) * sides;
+    }
+
+    sum
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<HashSet<Vec2>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_regions(&parse_input(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
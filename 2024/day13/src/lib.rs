@@ -0,0 +1,99 @@
+use aoc_common::{Answer, Error, Result, Solution};
+use regex::Regex;
+
+pub type PuzzleInput = ((i64, i64), (i64, i64), (i64, i64));
+
+fn parse_input(s: &str) -> Result<Vec<PuzzleInput>> {
+    let mut values: Vec<PuzzleInput> = Vec::new();
+
+    let re = Regex::new(r"X[+=](\d+), Y[+=](\d+)").unwrap();
+
+    let mut values_raw: Vec<(i64, i64)> = Vec::new();
+
+    for line in s.split("\n") {
+        if line.is_empty() {
+            continue;
+        }
+
+        let (_, [v1, v2]) = re
+            .captures(line)
+            .ok_or_else(|| Error::parse(format!("Invalid line {}", line)))?
+            .extract();
+        values_raw.push((v1.parse()?, v2.parse()?));
+    }
+
+    // Two buttons and a prize per machine.
+    if values_raw.is_empty() || !values_raw.len().is_multiple_of(3) {
+        return Err(Error::parse("Every machine needs two buttons and a prize"));
+    }
+    for machine in values_raw.chunks(3) {
+        values.push((machine[0], machine[1], machine[2]));
+    }
+
+    Ok(values)
+}
+
+fn puzzle(input: &[PuzzleInput], offset: bool) -> u64 {
+    let mut sum: u64 = 0;
+    for ((ax, ay), (bx, by), (tx, ty)) in input {
+        let offset_amount: i64 = 10000000000000;
+
+        let (tx, ty) = if offset {
+            (*tx + offset_amount, *ty + offset_amount)
+        } else {
+            (*tx, *ty)
+        };
+
+        let inv_scale: f64 = (ax * by - bx * ay) as f64;
+
+        // let (tx, ty): (u128, u128) = ((*tx).into(), (*ty).into());
+
+        // scale * (tx * by - ty * bx) as f64,
+        // scale * (ty * ax - tx * ay) as f64,
+
+        let scale: f64 = 1. / inv_scale;
+        let (a, b) = (
+            scale * (tx * by - ty * bx) as f64,
+            scale * (ty * ax - tx * ay) as f64,
+            // scale * calc_disc(*tx, *ty, *bx, *by),
+            // scale * calc_disc(*ty, *tx, *ay, *ax),
+        );
+
+        if a < 0. || b < 0. {
+            continue;
+        }
+
+        let eps = 0.01;
+        let ar = a.round();
+        let br = b.round();
+
+        if (ar - a).abs() >= eps || (br - b).abs() >= eps {
+            continue;
+        }
+
+        let ai: u64 = ar as u64;
+        let bi: u64 = br as u64;
+
+        sum += ai * 3 + bi;
+    }
+
+    sum
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<PuzzleInput>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(puzzle(input, false).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(puzzle(input, true).into())
+    }
+}
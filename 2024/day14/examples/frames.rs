@@ -0,0 +1,56 @@
+//! Dumps every second of the robots' movement to `Output/image<N>.png`, 1000
+//! frames at a time. Press enter for the next batch, anything else to stop.
+//! Takes the input file and optionally `--width W --height H` for floors
+//! other than the real one.
+use aoc2024_day14::{position, Day14};
+use aoc_common::image::{Picture, BLACK, WHITE};
+use aoc_common::{read_input, Params, Solution, Vec2};
+
+use std::env;
+use std::fs;
+use std::io::stdin;
+use std::process;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 2 || !args.len().is_multiple_of(2) {
+        eprintln!("Usage: frames INPUT [--width W] [--height H]");
+        process::exit(2);
+    }
+
+    let mut params = Params::new();
+    for pair in args[2..].chunks(2) {
+        params.set(pair[0].trim_start_matches("--"), pair[1].as_str());
+    }
+
+    let ((width, height), input) = Day14::parse_with(&read_input(&args[1])?, &params)?;
+    fs::create_dir_all("Output")?;
+
+    let mut total: i32 = 1;
+
+    loop {
+        for _ in 0..1000 {
+            let robots = input.iter().map(|robot| {
+                let (px, py) = position(width, height, robot, total);
+                Vec2::new(px, py)
+            });
+            Picture::blank(width, height, 1, BLACK)
+                .mark(robots, WHITE)
+                .save_png(format!("Output/image{}.png", total))?;
+
+            println!("Current: {}", total);
+
+            total += 1;
+        }
+
+        let mut user_input = String::new();
+        stdin().read_line(&mut user_input).expect("Not a string");
+
+        match user_input.chars().next() {
+            Some('\n') => continue,
+            _ => break,
+        }
+    }
+
+    Ok(())
+}
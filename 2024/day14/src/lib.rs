@@ -0,0 +1,132 @@
+use aoc_common::{Answer, Error, Params, Result, Solution};
+use regex::Regex;
+
+use std::collections::HashSet;
+
+pub type Robot = ((i32, i32), (i32, i32));
+
+fn parse_input(s: &str) -> Result<Vec<Robot>> {
+    let mut values: Vec<Robot> = Vec::new();
+
+    let re = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
+
+    for line in s.split("\n") {
+        if line.is_empty() {
+            continue;
+        }
+
+        for (_, [px, py, vx, vy]) in re.captures_iter(line).map(|s| s.extract()) {
+            let pxi: i32 = px.parse()?;
+            let pyi: i32 = py.parse()?;
+            let vxi: i32 = vx.parse()?;
+            let vyi: i32 = vy.parse()?;
+            values.push(((pxi, pyi), (vxi, vyi)));
+        }
+    }
+
+    Ok(values)
+}
+
+fn get_quadrant(width: i32, height: i32, pos: (i32, i32)) -> (i32, i32, i32, i32) {
+    let (px, py) = pos;
+
+    let left = px < (width / 2);
+    let right = px >= ((width + 1) / 2);
+    let top = py < (height / 2);
+    let bottom = py >= ((height + 1) / 2);
+
+    if left && top {
+        (1, 0, 0, 0)
+    } else if left && bottom {
+        (0, 0, 1, 0)
+    } else if right && top {
+        (0, 1, 0, 0)
+    } else if right && bottom {
+        (0, 0, 0, 1)
+    } else {
+        (0, 0, 0, 0)
+    }
+}
+
+fn part1(width: i32, height: i32, input: &[Robot]) -> u64 {
+    let mut quadrants = (0, 0, 0, 0);
+
+    for robot in input {
+        let ((px, py), (vx, vy)) = robot;
+        let mut npx = (px + (vx * 100)) % width;
+        let mut npy = (py + (vy * 100)) % height;
+
+        if npx < 0 {
+            npx += width;
+        }
+
+        if npy < 0 {
+            npy += height;
+        }
+
+        let (tl, tr, bl, br) = get_quadrant(width, height, (npx, npy));
+        let (stl, str, sbl, sbr) = quadrants;
+        quadrants = (stl + tl, str + tr, sbl + bl, sbr + br);
+    }
+
+    let (tl, tr, bl, br) = quadrants;
+    (tl * tr * bl * br) as u64
+}
+
+/// Where `robot` ends up after `seconds` seconds on a `width` x `height` floor.
+pub fn position(width: i32, height: i32, robot: &Robot, seconds: i32) -> (i32, i32) {
+    let ((px, py), (vx, vy)) = *robot;
+
+    // Wide enough for any speed over any number of seconds.
+    let moved = |p: i32, v: i32, size: i32| {
+        (p as i64 + v as i64 * seconds as i64).rem_euclid(size as i64) as i32
+    };
+    (moved(px, vx, width), moved(py, vy, height))
+}
+
+// The tree shows up on the first frame where no two robots overlap
+fn part2(width: i32, height: i32, input: &[Robot]) -> Result<u64> {
+    let mut positions: HashSet<(i32, i32)> = HashSet::new();
+
+    for seconds in 1..=(width * height) {
+        positions.clear();
+        if input
+            .iter()
+            .all(|robot| positions.insert(position(width, height, robot, seconds)))
+        {
+            return Ok(seconds as u64);
+        }
+    }
+
+    Err(Error::solve("The robots never spread out"))
+}
+
+const WIDTH: i32 = 101;
+const HEIGHT: i32 = 103;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = ((i32, i32), Vec<Robot>);
+    const PARAMS: &'static [&'static str] = &["width", "height"];
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let (width, height) = (params.get("width", WIDTH)?, params.get("height", HEIGHT)?);
+        // Part 2 looks at up to `width * height` seconds.
+        if width <= 0 || height <= 0 || width.checked_mul(height).is_none() {
+            return Err(Error::Param(format!(
+                "--width {} --height {}",
+                width, height
+            )));
+        }
+        Ok(((width, height), parse_input(input)?))
+    }
+
+    fn part1(((width, height), input): &Self::Input) -> Result<Answer> {
+        Ok(part1(*width, *height, input).into())
+    }
+
+    fn part2(((width, height), input): &Self::Input) -> Result<Answer> {
+        Ok(part2(*width, *height, input)?.into())
+    }
+}
@@ -0,0 +1,75 @@
+//! Animates the robot pushing boxes around the warehouse. Takes the input
+//! file, `--wide` for the second part's doubled warehouse, `--gif PATH` to
+//! save the animation instead of playing it, `--every N` to keep only every
+//! Nth move of the GIF, and an optional delay between frames in
+//! milliseconds.
+use aoc2024_day15::{gps, step, Day15};
+use aoc_common::image::{Gif, Palette, Picture};
+use aoc_common::render::{Animation, Color, Render, Style};
+use aoc_common::{read_input, Solution};
+
+use std::env;
+use std::process;
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut path = None;
+    let mut gif_path = None;
+    let mut wide = false;
+    let mut every = 1;
+    let mut delay = 50;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--wide" => wide = true,
+            "--gif" => gif_path = args.next(),
+            "--every" => every = args.next().ok_or("Missing value for --every")?.parse()?,
+            _ if path.is_none() => path = Some(arg),
+            _ => delay = arg.parse()?,
+        }
+    }
+    let Some(path) = path else {
+        eprintln!("Usage: warehouse INPUT [--wide] [--gif PATH [--every N]] [DELAY_MS]");
+        process::exit(2);
+    };
+
+    let (narrow, doubled) = Day15::parse(&read_input(&path)?)?;
+    let (mut pos, mut grid, moves) = if wide { doubled } else { narrow };
+    let delay = Duration::from_millis(delay);
+
+    if let Some(gif_path) = gif_path {
+        let palette = Palette::new([32, 32, 32])
+            .with('#', [128, 128, 128])
+            .with('O', [200, 160, 60])
+            .with('[', [200, 160, 60])
+            .with(']', [200, 160, 60]);
+        let mut gif = Gif::create(gif_path, delay)?;
+        for (i, dir) in moves.iter().enumerate() {
+            pos = step(pos, &mut grid, *dir);
+            if i % every.max(1) == 0 || i + 1 == moves.len() {
+                gif.push(Picture::from_grid(&grid, &palette, 4).mark([pos], [220, 40, 40]))?;
+            }
+        }
+        gif.finish()?;
+        return Ok(());
+    }
+
+    let mut animation = Animation::stdout(delay);
+    for (i, dir) in moves.iter().enumerate() {
+        pos = step(pos, &mut grid, *dir);
+        let frame = Render::new(&grid, |c| match c {
+            '#' => Style::glyph('#').with_fg(Color::Gray),
+            'O' | '[' | ']' => Style::glyph(*c).with_fg(Color::Yellow),
+            _ => Style::glyph(' '),
+        })
+        .overlay([pos], Style::glyph(dir.arrow()).with_fg(Color::Red));
+        animation.frame(format!(
+            "{frame}move {} of {}, GPS {}\n",
+            i + 1,
+            moves.len(),
+            gps(&grid)
+        ))?;
+    }
+
+    Ok(())
+}
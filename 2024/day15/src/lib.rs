@@ -0,0 +1,185 @@
+use aoc_common::{Answer, Direction, Error, Grid, Result, Solution, Vec2};
+
+pub type Warehouse = (Vec2, Grid<char>, Vec<Direction>);
+
+fn parse_input(s: &str, double_width: bool) -> Result<Warehouse> {
+    let (map, moves) = s
+        .split_once("\n\n")
+        .ok_or_else(|| Error::parse("Missing the moves after the map"))?;
+
+    let map = if double_width {
+        let mut wide = String::new();
+        for char in map.chars() {
+            wide.push_str(match char {
+                '.' => "..",
+                '#' => "##",
+                'O' => "[]",
+                '@' => "@.",
+                '\n' => "\n",
+                _ => return Err(Error::parse(format!("Unexpected tile {char:?}"))),
+            });
+        }
+        wide
+    } else {
+        map.to_string()
+    };
+
+    let mut grid = Grid::parse(&map)?;
+    let start_pos = grid
+        .find('@')
+        .ok_or_else(|| Error::parse("No robot on the map"))?;
+    grid[start_pos] = '.';
+
+    let movement = moves
+        .chars()
+        .filter(|c| *c != '\n')
+        .map(Direction::try_from)
+        .collect::<Result<_>>()?;
+
+    Ok((start_pos, grid, movement))
+}
+
+fn move_boxes(check_pos: &Vec2, dir: Direction, grid: &mut Grid<char>) -> bool {
+    let movement_pos = *check_pos + dir;
+
+    let contains_2 = grid[movement_pos];
+    match contains_2 {
+        '.' => {
+            grid[movement_pos] = 'O';
+            grid[*check_pos] = '.';
+            true
+        }
+        'O' => {
+            if move_boxes(&movement_pos, dir, grid) {
+                grid[movement_pos] = grid[*check_pos];
+                grid[*check_pos] = '.';
+                return true;
+            }
+            false
+        }
+        _ => false,
+    }
+}
+
+fn move_boxes_2(check_pos: &Vec2, dir: Direction, grid: &mut Grid<char>, can_move: bool) -> bool {
+    let movement_pos = *check_pos + dir;
+
+    let contains_2 = grid[movement_pos];
+    match contains_2 {
+        '.' => {
+            if can_move {
+                grid[movement_pos] = grid[*check_pos];
+                grid[*check_pos] = '.';
+            }
+            true
+        }
+        '[' | ']' => {
+            let char = contains_2;
+            if dir.is_vertical() {
+                let (left_x, right_x) = match char {
+                    '[' => (movement_pos.x, movement_pos.x + 1),
+                    ']' => (movement_pos.x - 1, movement_pos.x),
+                    _ => unreachable!(),
+                };
+
+                let left_pos = Vec2 {
+                    x: left_x,
+                    y: movement_pos.y,
+                };
+
+                let right_pos = Vec2 {
+                    x: right_x,
+                    y: movement_pos.y,
+                };
+
+                let left = move_boxes_2(&left_pos, dir, grid, can_move);
+
+                let right = move_boxes_2(&right_pos, dir, grid, can_move);
+
+                if left && right {
+                    if can_move {
+                        grid[movement_pos] = grid[*check_pos];
+
+                        grid[*check_pos] = '.';
+                    }
+
+                    return true;
+                }
+            } else {
+                if move_boxes_2(&movement_pos, dir, grid, can_move) {
+                    if can_move {
+                        grid[movement_pos] = grid[*check_pos];
+
+                        grid[*check_pos] = '.';
+                    }
+
+                    return true;
+                }
+            }
+            false
+        }
+        _ => false,
+    }
+}
+
+/// Moves the robot at `pos` one step, pushing any boxes in the way, and
+/// returns where it ends up.
+pub fn step(pos: Vec2, grid: &mut Grid<char>, dir: Direction) -> Vec2 {
+    let new_pos = pos + dir;
+
+    let moved = match grid[new_pos] {
+        '#' => false,
+        'O' => move_boxes(&new_pos, dir, grid),
+        '[' | ']' => move_boxes_2(&pos, dir, grid, false) && move_boxes_2(&pos, dir, grid, true),
+        _ => true,
+    };
+
+    if moved {
+        new_pos
+    } else {
+        pos
+    }
+}
+
+/// The sum of the boxes' GPS coordinates, measured from their left edge.
+pub fn gps(grid: &Grid<char>) -> u64 {
+    let mut sum: u64 = 0;
+    for (pos, value) in grid.iter() {
+        if *value != 'O' && *value != '[' {
+            continue;
+        }
+
+        sum += (pos.y * 100 + pos.x) as u64;
+    }
+
+    sum
+}
+
+fn simulate((start_pos, grid, moves): &Warehouse) -> u64 {
+    let mut current_pos = *start_pos;
+    let mut new_grid = grid.clone();
+
+    for dir in moves {
+        current_pos = step(current_pos, &mut new_grid, *dir);
+    }
+
+    gps(&new_grid)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Warehouse, Warehouse);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((parse_input(input, false)?, parse_input(input, true)?))
+    }
+
+    fn part1((warehouse, _): &Self::Input) -> Result<Answer> {
+        Ok(simulate(warehouse).into())
+    }
+
+    fn part2((_, wide): &Self::Input) -> Result<Answer> {
+        Ok(simulate(wide).into())
+    }
+}
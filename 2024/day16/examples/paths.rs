@@ -0,0 +1,95 @@
+//! Animates the reindeer along one of the best paths through the maze, then
+//! shows every tile on any of them. Takes the input file, `--gif PATH` to
+//! save the animation instead of playing it, `--every N` to keep only every
+//! Nth step of the GIF, and an optional delay between frames in milliseconds.
+use aoc2024_day16::{best_ends, routes, Day16};
+use aoc_common::image::{Gif, Palette, Picture};
+use aoc_common::render::{Animation, Color, Render, Style};
+use aoc_common::{read_input, Solution};
+
+use std::collections::HashSet;
+use std::env;
+use std::process;
+use std::time::Duration;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut path = None;
+    let mut gif_path = None;
+    let mut every = 1;
+    let mut delay = 20;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--gif" => gif_path = args.next(),
+            "--every" => every = args.next().ok_or("Missing value for --every")?.parse()?,
+            _ if path.is_none() => path = Some(arg),
+            _ => delay = arg.parse()?,
+        }
+    }
+    let Some(path) = path else {
+        eprintln!("Usage: paths INPUT [--gif PATH [--every N]] [DELAY_MS]");
+        process::exit(2);
+    };
+    let delay = Duration::from_millis(delay);
+
+    let (start_pos, end_pos, grid) = Day16::parse(&read_input(&path)?)?;
+    let routes = routes(start_pos, &grid);
+    let Some((score, ends)) = best_ends(&routes, end_pos) else {
+        println!("There's no way to the end");
+        return Ok(());
+    };
+    let path = routes.path(&ends[0]).unwrap_or_default();
+    let seats: HashSet<_> = routes
+        .on_shortest_paths(ends.clone())
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect();
+
+    if let Some(gif_path) = gif_path {
+        let palette = Palette::new([32, 32, 32]).with('#', [128, 128, 128]);
+        let maze =
+            || Picture::from_grid(&grid, &palette, 4).mark([start_pos, end_pos], [40, 200, 220]);
+        let mut gif = Gif::create(gif_path, delay)?;
+        for (i, (pos, _)) in path.iter().enumerate().step_by(every.max(1)) {
+            let trail = path[..i].iter().map(|(pos, _)| *pos);
+            gif.push(
+                maze()
+                    .mark(trail, [40, 160, 40])
+                    .mark([*pos], [230, 200, 40]),
+            )?;
+        }
+        gif.push(maze().mark(seats.iter().copied(), [40, 160, 40]))?;
+        gif.finish()?;
+        return Ok(());
+    }
+
+    let maze = || {
+        Render::new(&grid, |c| match c {
+            '#' => Style::glyph('#').with_fg(Color::Gray),
+            _ => Style::glyph(' '),
+        })
+        .overlay([start_pos], Style::glyph('S').with_fg(Color::Cyan))
+        .overlay([end_pos], Style::glyph('E').with_fg(Color::Cyan))
+    };
+
+    let mut animation = Animation::stdout(delay);
+    for (i, state) in path.iter().enumerate() {
+        let (pos, dir) = *state;
+        let trail = path[..i].iter().map(|(pos, _)| *pos);
+        let frame = maze()
+            .overlay(trail, Style::bg(Color::Green))
+            .overlay([pos], Style::glyph(dir.arrow()).with_fg(Color::Yellow));
+        animation.frame(format!(
+            "{frame}score {}\n",
+            routes.distance(state).unwrap_or(0)
+        ))?;
+    }
+
+    let frame = maze().overlay(
+        seats.iter().copied(),
+        Style::glyph('O').with_bg(Color::Green),
+    );
+    animation.frame(format!("{frame}score {score}, {} seats\n", seats.len()))?;
+
+    Ok(())
+}
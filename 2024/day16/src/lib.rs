@@ -0,0 +1,79 @@
+use aoc_common::{dijkstra, Answer, Direction, Error, Grid, Result, Search, Solution, Vec2};
+use std::collections::HashSet;
+
+fn parse_input(s: &str) -> Result<(Vec2, Vec2, Grid<char>)> {
+    let mut grid = Grid::parse(s)?;
+    let start_pos = grid.find('S').ok_or_else(|| Error::parse("No start"))?;
+    let end_pos = grid.find('E').ok_or_else(|| Error::parse("No end"))?;
+    grid[start_pos] = '.';
+    grid[end_pos] = '.';
+
+    Ok((start_pos, end_pos, grid))
+}
+
+pub type Reindeer = (Vec2, Direction);
+
+/// Every shortest route from the start, facing east. Stepping forward costs
+/// 1, turning on the spot 1000.
+pub fn routes(start_pos: Vec2, grid: &Grid<char>) -> Search<Reindeer> {
+    dijkstra((start_pos, Direction::Right), |&(pos, dir)| {
+        let mut next = vec![
+            ((pos, dir.turn_left()), 1000),
+            ((pos, dir.turn_right()), 1000),
+        ];
+        if grid.get(&(pos + dir)).is_some_and(|c| *c != '#') {
+            next.push(((pos + dir, dir), 1));
+        }
+        next
+    })
+}
+
+/// The lowest score to reach `end_pos`, and the ways of facing it has then.
+pub fn best_ends(routes: &Search<Reindeer>, end_pos: Vec2) -> Option<(u64, Vec<Reindeer>)> {
+    let ends = Direction::ALL.map(|dir| (end_pos, dir));
+    let lowest_score = ends.iter().filter_map(|end| routes.distance(end)).min()?;
+    let best = ends
+        .into_iter()
+        .filter(|end| routes.distance(end) == Some(lowest_score))
+        .collect();
+
+    Some((lowest_score, best))
+}
+
+fn search(start_pos: Vec2, end_pos: Vec2, grid: &Grid<char>) -> Result<(u64, HashSet<Reindeer>)> {
+    let routes = routes(start_pos, grid);
+    let (lowest_score, best) =
+        best_ends(&routes, end_pos).ok_or_else(|| Error::solve("No way to the end"))?;
+    Ok((lowest_score, routes.on_shortest_paths(best)))
+}
+
+fn part1(start_pos: Vec2, end_pos: Vec2, grid: &Grid<char>) -> Result<u64> {
+    Ok(search(start_pos, end_pos, grid)?.0)
+}
+
+fn part2(start_pos: Vec2, end_pos: Vec2, grid: &Grid<char>) -> Result<usize> {
+    let (_, seats) = search(start_pos, end_pos, grid)?;
+    Ok(seats
+        .iter()
+        .map(|(pos, _)| *pos)
+        .collect::<HashSet<_>>()
+        .len())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = (Vec2, Vec2, Grid<char>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((start_pos, end_pos, grid): &Self::Input) -> Result<Answer> {
+        Ok(part1(*start_pos, *end_pos, grid)?.into())
+    }
+
+    fn part2((start_pos, end_pos, grid): &Self::Input) -> Result<Answer> {
+        Ok(part2(*start_pos, *end_pos, grid)?.into())
+    }
+}
@@ -1,39 +1,32 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
+use aoc_common::{Answer, Error, Result, Solution};
 
-fn parse_input(file_name: &str) -> (Vec<u8>, u64, u64, u64) {
-    let path = Path::new(file_name);
-    let display = path.display();
-
-    let mut file = match File::open(path) {
-        Err(err) => panic!("Couldn't open {}: {}", display, err),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-
-    if file.read_to_string(&mut s).is_err() {
-        panic!("Couldn't read {}", file_name);
-    }
+const DEBUG_PRINT: bool = false;
 
+fn parse_input(s: &str) -> Result<(Vec<u8>, u64, u64, u64)> {
     let mut operations: Vec<u8> = Vec::new();
 
     let lines: Vec<&str> = s.split("\n").collect();
 
-    let reg_a: u64 = lines[0][12..].parse().unwrap();
-    let reg_b: u64 = lines[1][12..].parse().unwrap();
-    let reg_c: u64 = lines[2][12..].parse().unwrap();
+    let field = |index: usize, prefix: &str| {
+        lines
+            .get(index)
+            .and_then(|line| line.strip_prefix(prefix))
+            .ok_or_else(|| Error::parse(format!("Line {} should start {:?}", index + 1, prefix)))
+    };
+
+    let reg_a: u64 = field(0, "Register A: ")?.parse()?;
+    let reg_b: u64 = field(1, "Register B: ")?.parse()?;
+    let reg_c: u64 = field(2, "Register C: ")?.parse()?;
 
-    for char in lines[4][9..].split(",") {
-        operations.push(char.parse().unwrap());
+    for char in field(4, "Program: ")?.split(",") {
+        operations.push(char.parse()?);
     }
 
-    (operations, reg_a, reg_b, reg_c)
+    Ok((operations, reg_a, reg_b, reg_c))
 }
 
 fn check_instr(
-    input: &Vec<u8>,
+    input: &[u8],
     reg_a: &mut u64,
     reg_b: &mut u64,
     reg_c: &mut u64,
@@ -43,7 +36,7 @@ fn check_instr(
     let literal_operand = input[*instr_counter + 1];
 
     let combo_operand: u64 = match literal_operand {
-        0 | 1 | 2 | 3 => literal_operand as u64,
+        0..=3 => literal_operand as u64,
         4 => *reg_a,
         5 => *reg_b,
         6 => *reg_c,
@@ -57,10 +50,10 @@ fn check_instr(
     match opcode {
         0 => {
             let denom = u64::pow(2, combo_operand as u32);
-            *reg_a = *reg_a / denom;
+            *reg_a /= denom;
         }
         1 => {
-            *reg_b = *reg_b ^ (literal_operand as u64);
+            *reg_b ^= literal_operand as u64;
         }
         2 => *reg_b = combo_operand % 8,
         3 => {
@@ -70,7 +63,7 @@ fn check_instr(
             }
         }
         4 => {
-            *reg_b = *reg_b ^ *reg_c;
+            *reg_b ^= *reg_c;
         }
         5 => {
             return_value = Some((combo_operand % 8) as u8);
@@ -87,14 +80,14 @@ fn check_instr(
     }
 
     if should_increase {
-        *instr_counter = *instr_counter + 2;
+        *instr_counter += 2;
     }
 
     return_value
 }
 
-fn part1(input: &Vec<u8>, reg_a: u64, reg_b: u64, reg_c: u64) {
-    let mut has_printed = false;
+fn part1(input: &[u8], reg_a: u64, reg_b: u64, reg_c: u64) -> String {
+    let mut output: Vec<String> = Vec::new();
     let mut instr_counter = 0;
 
     let mut reg_a = reg_a;
@@ -103,23 +96,21 @@ fn part1(input: &Vec<u8>, reg_a: u64, reg_b: u64, reg_c: u64) {
 
     while instr_counter < input.len() {
         if let Some(x) = check_instr(
-            &input,
+            input,
             &mut reg_a,
             &mut reg_b,
             &mut reg_c,
             &mut instr_counter,
         ) {
-            if has_printed {
-                print!(",");
-            }
-            print!("{}", x);
-            has_printed = true;
+            output.push(x.to_string());
         }
     }
+
+    output.join(",")
 }
 
 // Only works for true input
-fn part2(input: &Vec<u8>, reg_b: u64, reg_c: u64) -> u64 {
+fn part2(input: &[u8], reg_b: u64, reg_c: u64) -> u64 {
     for initial_a in 0.. {
         let patterns: Vec<u64> = vec![
             0b101000011110110000001110011011,
@@ -143,14 +134,16 @@ fn part2(input: &Vec<u8>, reg_b: u64, reg_c: u64) -> u64 {
 
             while instr_counter < input.len() {
                 if let Some(x) = check_instr(
-                    &input,
+                    input,
                     &mut reg_a,
                     &mut reg_b,
                     &mut reg_c,
                     &mut instr_counter,
                 ) {
                     if current_check >= input.len() {
-                        println!("Exceeded: {starting_value}");
+                        if DEBUG_PRINT {
+                            println!("Exceeded: {starting_value}");
+                        }
                         valid = false;
                     }
 
@@ -165,7 +158,7 @@ fn part2(input: &Vec<u8>, reg_b: u64, reg_c: u64) -> u64 {
             if current_check == input.len() && valid {
                 return starting_value;
             }
-            if current_check >= 14 && valid {
+            if DEBUG_PRINT && current_check >= 14 && valid {
                 let valid_digits = &input[0..current_check];
                 println!(
                     "Checked: {starting_value} | Correct: {}/{} | {:?}",
@@ -180,16 +173,20 @@ fn part2(input: &Vec<u8>, reg_b: u64, reg_c: u64) -> u64 {
     0
 }
 
-fn main() {
-    // let (operations, reg_a, reg_b, reg_c) = parse_input("Example.txt");
-    // let (operations, reg_a, reg_b, reg_c) = parse_input("Example2.txt");
-    // let (operations, reg_a, reg_b, reg_c) = parse_input("TestInput.txt");
-    let (operations, reg_a, reg_b, reg_c) = parse_input("Input.txt");
+pub struct Day17;
 
-    print!("Part 1: ");
-    part1(&operations, reg_a, reg_b, reg_c);
-    println!("");
+impl Solution for Day17 {
+    type Input = (Vec<u8>, u64, u64, u64);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
 
-    let p2 = part2(&operations, reg_b, reg_c);
-    println!("Part 2: {p2}");
+    fn part1((operations, reg_a, reg_b, reg_c): &Self::Input) -> Result<Answer> {
+        Ok(part1(operations, *reg_a, *reg_b, *reg_c).into())
+    }
+
+    fn part2((operations, _, reg_b, reg_c): &Self::Input) -> Result<Answer> {
+        Ok(part2(operations, *reg_b, *reg_c).into())
+    }
 }
@@ -0,0 +1,125 @@
+use aoc_common::render::{Color, Render, Style};
+use aoc_common::{astar, Answer, Error, Grid, Params, Result, Solution, Vec2};
+use std::collections::HashSet;
+
+const DEBUG_PRINT: bool = false;
+
+fn parse_input(s: &str) -> Result<Vec<Vec2>> {
+    let mut values: Vec<Vec2> = Vec::new();
+
+    for line in s.split("\n") {
+        if line.is_empty() {
+            continue;
+        }
+        let (x, y) = line
+            .split_once(',')
+            .ok_or_else(|| Error::parse(format!("Invalid position {}", line)))?;
+        values.push(Vec2 {
+            x: x.parse()?,
+            y: y.parse()?,
+        })
+    }
+
+    Ok(values)
+}
+
+/// The memory space with the first `bytes` bytes fallen.
+fn corrupt(size: i32, input: &[Vec2], bytes: usize) -> Result<Grid<bool>> {
+    let side = size
+        .checked_add(1)
+        .ok_or_else(|| Error::parse(format!("Invalid size {}", size)))?;
+    let mut grid = Grid::new(side, side, false)?;
+    for pos in input.iter().take(bytes) {
+        if let Some(cell) = grid.get_mut(pos) {
+            *cell = true;
+        }
+    }
+    Ok(grid)
+}
+
+/// A shortest path from the top left corner to the bottom right one.
+fn shortest_path(grid: &Grid<bool>) -> Option<Vec<Vec2>> {
+    let exit = Vec2::new(grid.width() - 1, grid.height() - 1);
+    let search = astar(
+        Vec2::new(0, 0),
+        |pos| {
+            grid.neighbors4(pos)
+                .filter(|next| !grid[*next])
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        },
+        |pos| (i32::abs(exit.x - pos.x) + i32::abs(exit.y - pos.y)) as u64,
+        |pos| *pos == exit,
+    );
+    search.path(&exit)
+}
+
+fn part1(size: i32, input: &[Vec2], bytes: usize) -> Result<u64> {
+    let grid = corrupt(size, input, bytes)?;
+    let path = shortest_path(&grid).ok_or_else(|| Error::solve("The exit is cut off"))?;
+
+    if DEBUG_PRINT {
+        let render = Render::new(&grid, |corrupted| {
+            Style::glyph(if *corrupted { '#' } else { '.' })
+        });
+        print!(
+            "{}",
+            render.overlay(
+                path.iter().copied(),
+                Style::glyph('O').with_fg(Color::Green)
+            )
+        );
+    }
+
+    Ok(path.len().saturating_sub(1) as u64)
+}
+
+fn part2(size: i32, input: &[Vec2], bytes: usize) -> Result<Vec2> {
+    let mut grid = corrupt(size, input, bytes)?;
+    let mut path: HashSet<Vec2> = shortest_path(&grid).into_iter().flatten().collect();
+
+    for pos in input.iter().skip(bytes) {
+        let Some(cell) = grid.get_mut(pos) else {
+            continue;
+        };
+        *cell = true;
+
+        // Only a byte landing on the current path can cut the exit off.
+        if !path.contains(pos) {
+            continue;
+        }
+        match shortest_path(&grid) {
+            Some(new_path) => path = new_path.into_iter().collect(),
+            None => return Ok(*pos),
+        }
+    }
+
+    Ok(Vec2 { x: -1, y: -1 })
+}
+
+const SIZE: i32 = 70;
+const BYTES: usize = 1024;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = ((i32, usize), Vec<Vec2>);
+    const PARAMS: &'static [&'static str] = &["size", "bytes"];
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let settings = (params.get("size", SIZE)?, params.get("bytes", BYTES)?);
+        if settings.0 < 0 {
+            return Err(Error::Param(format!("--size {}", settings.0)));
+        }
+        Ok((settings, parse_input(input)?))
+    }
+
+    fn part1(((size, bytes), input): &Self::Input) -> Result<Answer> {
+        Ok(part1(*size, input, *bytes)?.into())
+    }
+
+    fn part2(((size, bytes), input): &Self::Input) -> Result<Answer> {
+        let p2 = part2(*size, input, *bytes)?;
+        Ok(format!("{},{}", p2.x, p2.y).into())
+    }
+}
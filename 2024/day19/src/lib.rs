@@ -0,0 +1,152 @@
+use aoc_common::{Answer, Error, Result, Solution};
+// use std::collections::BinaryHeap;
+use std::collections::HashMap;
+
+const DEBUG_PRINT: bool = false;
+// use std::collections::VecDeque;
+// use std::hash::{Hash, Hasher};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum State {
+    White,
+    Blue,
+    Black,
+    Red,
+    Green,
+}
+
+pub type Pattern = Vec<State>;
+
+fn parse_pattern(s: &str) -> Result<Pattern> {
+    s.chars()
+        .map(|c| match c {
+            'w' => Ok(State::White),
+            'u' => Ok(State::Blue),
+            'b' => Ok(State::Black),
+            'r' => Ok(State::Red),
+            'g' => Ok(State::Green),
+            _ => Err(Error::parse(format!("Unknown colour {:?}", c))),
+        })
+        .collect()
+}
+
+fn parse_input(s: &str) -> Result<(Vec<Pattern>, Vec<Pattern>)> {
+    let lines: Vec<&str> = s.split("\n").filter(|s| !s.is_empty()).collect();
+    let (first, rest) = lines
+        .split_first()
+        .ok_or_else(|| Error::parse("No towel patterns"))?;
+
+    let rules = first
+        .split(", ")
+        .map(parse_pattern)
+        .collect::<Result<Vec<_>>>()?;
+    let targets = rest
+        .iter()
+        .map(|target| parse_pattern(target))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((rules, targets))
+}
+
+fn print_sequence(seq: &[State]) {
+    for s in seq {
+        let c = match s {
+            State::White => 'w',
+            State::Blue => 'u',
+            State::Black => 'b',
+            State::Red => 'r',
+            State::Green => 'g',
+        };
+        print!("{c}");
+    }
+    println!();
+}
+
+fn check_rule(rule: &[State], target: &[State], start_index: usize) -> Option<usize> {
+    for i in 0..rule.len() {
+        let r = rule[i];
+        if start_index + i >= target.len() {
+            return None;
+        }
+
+        let t = target[start_index + i];
+
+        if r != t {
+            return None;
+        }
+    }
+
+    Some(start_index + rule.len())
+}
+
+fn check_target(
+    rules: &[Vec<State>],
+    target: &[State],
+    start_index: usize,
+    memory: &mut HashMap<Vec<State>, u64>,
+) -> Option<u64> {
+    if start_index == target.len() {
+        return Some(1);
+    }
+
+    if memory.contains_key(&target[start_index..]) {
+        return Some(*memory.get(&target[start_index..]).unwrap());
+    }
+
+    let mut count = 0;
+
+    for rule in rules {
+        if let Some(new_index) = check_rule(rule, target, start_index) {
+            if let Some(x) = check_target(rules, target, new_index, memory) {
+                count += x;
+            }
+        }
+    }
+
+    memory.insert(target[start_index..].to_vec(), count);
+
+    if count != 0 {
+        Some(count)
+    } else {
+        None
+    }
+}
+
+fn puzzle(rules: &[Vec<State>], targets: &[Vec<State>]) -> (u64, u64) {
+    let mut count = 0;
+    let mut total = 0;
+
+    let mut memory: HashMap<Vec<State>, u64> = HashMap::new();
+
+    for target in targets {
+        if let Some(x) = check_target(rules, target, 0, &mut memory) {
+            count += 1;
+            total += x;
+            if DEBUG_PRINT {
+                print!("Seq: ");
+                print_sequence(target);
+                println!("{}", x);
+            }
+        }
+    }
+
+    (count, total)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Vec<Pattern>, Vec<Pattern>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((rules, targets): &Self::Input) -> Result<Answer> {
+        Ok(puzzle(rules, targets).0.into())
+    }
+
+    fn part2((rules, targets): &Self::Input) -> Result<Answer> {
+        Ok(puzzle(rules, targets).1.into())
+    }
+}
@@ -0,0 +1,71 @@
+use aoc_common::{bfs, Answer, Error, Grid, Params, Result, Solution, Vec2};
+
+const DEBUG_PRINT: bool = false;
+
+fn parse_input(s: &str) -> Result<(Vec2, Vec2, Grid<char>)> {
+    let mut grid = Grid::parse(s)?;
+    let start_pos = grid.find('S').ok_or_else(|| Error::parse("No start"))?;
+    let end_pos = grid.find('E').ok_or_else(|| Error::parse("No end"))?;
+    grid[start_pos] = '.';
+    grid[end_pos] = '.';
+
+    Ok((start_pos, end_pos, grid))
+}
+
+/// The racetrack from start to end, with how far along it each tile is.
+fn track(start_pos: Vec2, end_pos: Vec2, grid: &Grid<char>) -> Result<Vec<(Vec2, u64)>> {
+    let search = bfs(start_pos, |pos| {
+        grid.neighbors4(pos)
+            .filter(|next| grid[*next] != '#')
+            .collect::<Vec<_>>()
+    });
+    let path = search
+        .path(&end_pos)
+        .ok_or_else(|| Error::solve("No way to the end"))?;
+    Ok((0..).zip(path).map(|(score, pos)| (pos, score)).collect())
+}
+
+/// Counts the cheats of up to `diff` picoseconds that save at least `save`.
+fn part1(start_pos: Vec2, end_pos: Vec2, grid: &Grid<char>, diff: i32, save: i32) -> Result<u64> {
+    let path = track(start_pos, end_pos, grid)?;
+    if DEBUG_PRINT {
+        println!("Track is {} long", path.len());
+    }
+
+    let valid_pair = |(p1, s1): &(Vec2, u64), (p2, s2): &(Vec2, u64)| {
+        let manhatten_distance = i32::abs(p1.x - p2.x) + i32::abs(p1.y - p2.y);
+        if manhatten_distance > diff || manhatten_distance < 2 {
+            return false;
+        }
+
+        (*s2 as i32) - (*s1 as i32) - manhatten_distance >= save
+    };
+
+    let mut pairs = 0;
+    for (i, p1) in path.iter().enumerate() {
+        pairs += path[i + 1..].iter().filter(|p2| valid_pair(p1, p2)).count() as u64;
+    }
+
+    Ok(pairs)
+}
+
+const SAVE: i32 = 100;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = (i32, (Vec2, Vec2, Grid<char>));
+    const PARAMS: &'static [&'static str] = &["save"];
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        Ok((params.get("save", SAVE)?, parse_input(input)?))
+    }
+
+    fn part1((save, (start_pos, end_pos, grid)): &Self::Input) -> Result<Answer> {
+        Ok(part1(*start_pos, *end_pos, grid, 2, *save)?.into())
+    }
+
+    fn part2((save, (start_pos, end_pos, grid)): &Self::Input) -> Result<Answer> {
+        Ok(part1(*start_pos, *end_pos, grid, 20, *save)?.into())
+    }
+}
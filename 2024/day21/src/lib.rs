@@ -1,13 +1,8 @@
+use aoc_common::{Answer, Error, Result, Solution, Vec2};
 use std::collections::HashMap;
-use std::collections::HashSet;
-use std::collections::VecDeque;
-use std::fs::File;
-use std::hash::{Hash, Hasher};
-use std::io::prelude::*;
-use std::path::Path;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
-enum State {
+pub enum State {
     State9,
     State8,
     State7,
@@ -18,34 +13,15 @@ enum State {
     State2,
     State1,
     State0,
-    StateUp,
-    StateLeft,
-    StateDown,
-    StateRight,
+    Up,
+    Left,
+    Down,
+    Right,
     StateA,
 }
 
-#[derive(Debug, Clone, Copy, Eq)]
-struct Vec2 {
-    x: i32,
-    y: i32,
-}
-
-impl PartialEq<Vec2> for Vec2 {
-    fn eq(&self, other: &Vec2) -> bool {
-        self.x == other.x && self.y == other.y
-    }
-}
-
-impl Hash for Vec2 {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.x.hash(state);
-        self.y.hash(state);
-    }
-}
-
-fn char_to_state(c: char) -> State {
-    match c {
+fn char_to_state(c: char) -> Result<State> {
+    Ok(match c {
         '0' => State::State0,
         '1' => State::State1,
         '2' => State::State2,
@@ -56,30 +32,16 @@ fn char_to_state(c: char) -> State {
         '7' => State::State7,
         '8' => State::State8,
         '9' => State::State9,
-        '^' => State::StateUp,
-        'v' => State::StateDown,
-        '<' => State::StateLeft,
-        '>' => State::StateRight,
+        '^' => State::Up,
+        'v' => State::Down,
+        '<' => State::Left,
+        '>' => State::Right,
         'A' => State::StateA,
-        _ => panic!("Unknown state"),
-    }
+        _ => return Err(Error::parse(format!("Unknown key {:?}", c))),
+    })
 }
 
-fn parse_input(file_name: &str) -> Vec<(String, Vec<State>)> {
-    let path = Path::new(file_name);
-    let display = path.display();
-
-    let mut file = match File::open(path) {
-        Err(err) => panic!("Couldn't open {}: {}", display, err),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-
-    if file.read_to_string(&mut s).is_err() {
-        panic!("Couldn't read {}", file_name);
-    }
-
+fn parse_input(s: &str) -> Result<Vec<(String, Vec<State>)>> {
     let mut inputs: Vec<(String, Vec<State>)> = Vec::new();
 
     for line in s.split("\n") {
@@ -87,15 +49,24 @@ fn parse_input(file_name: &str) -> Vec<(String, Vec<State>)> {
             continue;
         }
 
+        // Door codes are digits followed by A; the numeric part is needed
+        // for the complexity.
+        let valid = line
+            .strip_suffix('A')
+            .is_some_and(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()));
+        if !valid {
+            return Err(Error::parse(format!("Invalid code {}", line)));
+        }
+
         let mut input: Vec<State> = Vec::new();
 
         for c in line.chars() {
-            input.push(char_to_state(c));
+            input.push(char_to_state(c)?);
         }
         inputs.push((line.to_string(), input));
     }
 
-    inputs
+    Ok(inputs)
 }
 
 fn get_movement(
@@ -150,7 +121,7 @@ fn get_movement(
             }
         }
     }
-    return (move_left_first, move_right_last);
+    (move_left_first, move_right_last)
 }
 
 fn calculate_input(
@@ -203,7 +174,7 @@ fn calculate_input(
             }
             let values = calculate_input(
                 intermedary_state,
-                State::StateLeft,
+                State::Left,
                 mapping,
                 memory,
                 depth + 1,
@@ -211,7 +182,7 @@ fn calculate_input(
                 invalid_bottom_left,
             );
             cost += values;
-            intermedary_state = State::StateLeft;
+            intermedary_state = State::Left;
         }
     }
     if !move_right_last {
@@ -221,7 +192,7 @@ fn calculate_input(
             }
             let values = calculate_input(
                 intermedary_state,
-                State::StateRight,
+                State::Right,
                 mapping,
                 memory,
                 depth + 1,
@@ -229,7 +200,7 @@ fn calculate_input(
                 invalid_bottom_left,
             );
             cost += values;
-            intermedary_state = State::StateRight;
+            intermedary_state = State::Right;
         }
     }
     for _ in 0..down {
@@ -238,7 +209,7 @@ fn calculate_input(
         }
         let values = calculate_input(
             intermedary_state,
-            State::StateDown,
+            State::Down,
             mapping,
             memory,
             depth + 1,
@@ -246,7 +217,7 @@ fn calculate_input(
             invalid_bottom_left,
         );
         cost += values;
-        intermedary_state = State::StateDown;
+        intermedary_state = State::Down;
     }
     for _ in 0..up {
         if depth + 1 == max_depth {
@@ -254,7 +225,7 @@ fn calculate_input(
         }
         let values = calculate_input(
             intermedary_state,
-            State::StateUp,
+            State::Up,
             mapping,
             memory,
             depth + 1,
@@ -262,7 +233,7 @@ fn calculate_input(
             invalid_bottom_left,
         );
         cost += values;
-        intermedary_state = State::StateUp;
+        intermedary_state = State::Up;
     }
     if !move_left_first {
         for _ in 0..left {
@@ -271,7 +242,7 @@ fn calculate_input(
             }
             let values = calculate_input(
                 intermedary_state,
-                State::StateLeft,
+                State::Left,
                 mapping,
                 memory,
                 depth + 1,
@@ -279,7 +250,7 @@ fn calculate_input(
                 invalid_bottom_left,
             );
             cost += values;
-            intermedary_state = State::StateLeft;
+            intermedary_state = State::Left;
         }
     }
     if move_right_last {
@@ -289,7 +260,7 @@ fn calculate_input(
             }
             let values = calculate_input(
                 intermedary_state,
-                State::StateRight,
+                State::Right,
                 mapping,
                 memory,
                 depth + 1,
@@ -297,7 +268,7 @@ fn calculate_input(
                 invalid_bottom_left,
             );
             cost += values;
-            intermedary_state = State::StateRight;
+            intermedary_state = State::Right;
         }
     }
 
@@ -318,11 +289,11 @@ fn calculate_input(
 
     memory.insert(pair, cost);
 
-    return cost;
+    cost
 }
 
 fn calculate_inputs(
-    input: &Vec<State>,
+    input: &[State],
     mapping: &HashMap<State, Vec2>,
     memory: &mut HashMap<(State, State, u64), u64>,
     depth: u64,
@@ -350,7 +321,7 @@ fn calculate_inputs(
     cost
 }
 
-fn puzzle(inputs: &Vec<(String, Vec<State>)>, robot_controlled_direction: u64) -> u64 {
+fn puzzle(inputs: &[(String, Vec<State>)], robot_controlled_direction: u64) -> u64 {
     let keypad_input: HashMap<State, Vec2> = HashMap::from([
         (State::State7, Vec2 { x: 0, y: 0 }),
         (State::State8, Vec2 { x: 1, y: 0 }),
@@ -366,11 +337,11 @@ fn puzzle(inputs: &Vec<(String, Vec<State>)>, robot_controlled_direction: u64) -
     ]);
 
     let control_input: HashMap<State, Vec2> = HashMap::from([
-        (State::StateUp, Vec2 { x: 1, y: 0 }),
+        (State::Up, Vec2 { x: 1, y: 0 }),
         (State::StateA, Vec2 { x: 2, y: 0 }),
-        (State::StateLeft, Vec2 { x: 0, y: 1 }),
-        (State::StateDown, Vec2 { x: 1, y: 1 }),
-        (State::StateRight, Vec2 { x: 2, y: 1 }),
+        (State::Left, Vec2 { x: 0, y: 1 }),
+        (State::Down, Vec2 { x: 1, y: 1 }),
+        (State::Right, Vec2 { x: 2, y: 1 }),
     ]);
 
     let mut total: u64 = 0;
@@ -385,7 +356,7 @@ fn puzzle(inputs: &Vec<(String, Vec<State>)>, robot_controlled_direction: u64) -
                 get_movement(current_state, *i, &keypad_input, &invalid_pos);
 
             let current_pos = *keypad_input.get(&current_state).unwrap();
-            let target_pos = *keypad_input.get(&i).unwrap();
+            let target_pos = *keypad_input.get(i).unwrap();
 
             let left = i32::max(0, current_pos.x - target_pos.x);
             let right = i32::max(0, target_pos.x - current_pos.x);
@@ -394,28 +365,28 @@ fn puzzle(inputs: &Vec<(String, Vec<State>)>, robot_controlled_direction: u64) -
 
             if move_left_first {
                 for _ in 0..left {
-                    movement.push(State::StateLeft);
+                    movement.push(State::Left);
                 }
             }
             if !move_right_last {
                 for _ in 0..right {
-                    movement.push(State::StateRight);
+                    movement.push(State::Right);
                 }
             }
             for _ in 0..down {
-                movement.push(State::StateDown);
+                movement.push(State::Down);
             }
             for _ in 0..up {
-                movement.push(State::StateUp);
+                movement.push(State::Up);
             }
             if !move_left_first {
                 for _ in 0..left {
-                    movement.push(State::StateLeft);
+                    movement.push(State::Left);
                 }
             }
             if move_right_last {
                 for _ in 0..right {
-                    movement.push(State::StateRight);
+                    movement.push(State::Right);
                 }
             }
             movement.push(State::StateA);
@@ -438,13 +409,20 @@ fn puzzle(inputs: &Vec<(String, Vec<State>)>, robot_controlled_direction: u64) -
     total
 }
 
-fn main() {
-    // let inputs = parse_input("Example.txt");
-    let inputs = parse_input("Input.txt");
+pub struct Day21;
 
-    let p1 = puzzle(&inputs, 2);
-    println!("Part 1: {p1}");
+impl Solution for Day21 {
+    type Input = Vec<(String, Vec<State>)>;
 
-    let p2 = puzzle(&inputs, 25);
-    println!("Part 2: {p2}");
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(puzzle(input, 2).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(puzzle(input, 25).into())
+    }
 }
@@ -1,36 +1,22 @@
+use aoc_common::{Answer, Result, Solution};
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
 
 use circular_buffer::CircularBuffer;
 
-fn parse_input(file_name: &str) -> Vec<u64> {
-    let path = Path::new(file_name);
-    let display = path.display();
-
-    let mut file = match File::open(path) {
-        Err(err) => panic!("Couldn't open {}: {}", display, err),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-
-    if file.read_to_string(&mut s).is_err() {
-        panic!("Couldn't read {}", file_name);
-    }
+const DEBUG_PRINT: bool = false;
 
+fn parse_input(s: &str) -> Result<Vec<u64>> {
     let mut input: Vec<u64> = Vec::new();
     for line in s.split("\n") {
         if line.is_empty() {
             continue;
         }
 
-        input.push(line.parse().unwrap());
+        input.push(line.parse()?);
     }
 
-    input
+    Ok(input)
 }
 
 fn evolve_secret(secret: u64, iterations: i32) -> u64 {
@@ -44,14 +30,16 @@ fn evolve_secret(secret: u64, iterations: i32) -> u64 {
     let mul2 = s3 << 11;
     let s4 = (s3 ^ mul2) % 16777216;
 
-    return evolve_secret(s4, iterations - 1);
+    evolve_secret(s4, iterations - 1)
 }
 
-fn part1(inputs: &Vec<u64>) -> u64 {
+fn part1(inputs: &[u64]) -> u64 {
     let mut total = 0;
     for i in inputs {
         let value = evolve_secret(*i, 2000);
-        println!("{i} | {value}");
+        if DEBUG_PRINT {
+            println!("{i} | {value}");
+        }
         total += value;
     }
     total
@@ -82,7 +70,7 @@ fn track_changes(
         let mul2 = s3 << 11;
         let s4 = (s3 ^ mul2) % 16777216;
 
-        let diff = (((s4 % 10) as i8) - ((current % 10) as i8)) as i8;
+        let diff = ((s4 % 10) as i8) - ((current % 10) as i8);
         current = s4;
 
         buf.push_back(diff);
@@ -95,16 +83,14 @@ fn track_changes(
             );
             new_memory.insert(pair);
 
-            if !cache.contains_key(&pair) {
-                cache.insert(pair, (current % 10) as u8);
-            }
+            cache.entry(pair).or_insert((current % 10) as u8);
         }
     }
     memory.extend(&new_memory);
     cache
 }
 
-fn part2(inputs: &Vec<u64>) -> i64 {
+fn part2(inputs: &[u64]) -> i64 {
     // let mut current_sequence: Vec<i8> = vec![-9, -9, -9, -9];
     // let mut current_sequence: Vec<i8> = vec![-1, -1, 0, 2];
     let mut max = 0;
@@ -117,10 +103,11 @@ fn part2(inputs: &Vec<u64>) -> i64 {
     // println!("{}", memory.len());
     // println!("{all_maps:?}");
 
-    let mut count = 0;
     let length = memory.len();
-    for pair in memory {
-        println!("Checking: {pair:?} | {} | {}/{}", max, count, length);
+    for (count, pair) in memory.into_iter().enumerate() {
+        if DEBUG_PRINT {
+            println!("Checking: {pair:?} | {} | {}/{}", max, count, length);
+        }
 
         let mut total = 0;
         for m in all_maps.iter() {
@@ -146,21 +133,25 @@ fn part2(inputs: &Vec<u64>) -> i64 {
         // }
 
         max = i64::max(total, max);
-        count += 1;
     }
 
     max
 }
 
-fn main() {
-    // let inputs = parse_input("Example.txt");
-    // let inputs = parse_input("SecondExample.txt");
-    let inputs = parse_input("Input.txt");
-    // let inputs = parse_input("ThirdExample.txt");
-
-    // let p1 = part1(&inputs);
-    // println!("Part 1: {p1}");
-    //
-    let p2 = part2(&inputs);
-    println!("Part 2: {p2}");
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
 }
@@ -1,26 +1,12 @@
+use aoc_common::{Answer, Error, Result, Solution};
 use std::collections::BTreeSet;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
 
 use multimap::MultiMap;
 
-fn parse_input(file_name: &str) -> (MultiMap<i32, i32>, HashMap<i32, String>) {
-    let path = Path::new(file_name);
-    let display = path.display();
-
-    let mut file = match File::open(path) {
-        Err(err) => panic!("Couldn't open {}: {}", display, err),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-
-    if file.read_to_string(&mut s).is_err() {
-        panic!("Couldn't read {}", file_name);
-    }
+const DEBUG_PRINT: bool = false;
 
+fn parse_input(s: &str) -> Result<(MultiMap<i32, i32>, HashMap<i32, String>)> {
     let mut int_to_string: HashMap<i32, String> = HashMap::new();
     let mut string_to_int: HashMap<String, i32> = HashMap::new();
     let mut current_index = 0;
@@ -30,9 +16,9 @@ fn parse_input(file_name: &str) -> (MultiMap<i32, i32>, HashMap<i32, String>) {
             continue;
         }
 
-        let parts: Vec<&str> = line.split("-").collect();
-        let v1 = parts[0];
-        let v2 = parts[1];
+        let (v1, v2) = line
+            .split_once('-')
+            .ok_or_else(|| Error::parse(format!("Invalid connection {}", line)))?;
 
         if !string_to_int.contains_key(v1) {
             string_to_int.insert(v1.to_string(), current_index);
@@ -52,7 +38,11 @@ fn parse_input(file_name: &str) -> (MultiMap<i32, i32>, HashMap<i32, String>) {
         input.insert(*i2, *i1);
     }
 
-    (input, int_to_string)
+    if input.is_empty() {
+        return Err(Error::parse("No connections"));
+    }
+
+    Ok((input, int_to_string))
 }
 
 fn part1(input: &MultiMap<i32, i32>, int_to_string: &HashMap<i32, String>) -> u64 {
@@ -66,7 +56,7 @@ fn part1(input: &MultiMap<i32, i32>, int_to_string: &HashMap<i32, String>) -> u6
             for v in v1 {
                 let current_set: BTreeSet<i32> = BTreeSet::from([*i, *j, *v]);
 
-                if !is_clique(&current_set, &input) {
+                if !is_clique(&current_set, input) {
                     continue;
                 }
 
@@ -74,10 +64,7 @@ fn part1(input: &MultiMap<i32, i32>, int_to_string: &HashMap<i32, String>) -> u6
                     let a = int_to_string.get(i).unwrap();
                     let b = int_to_string.get(j).unwrap();
                     let c = int_to_string.get(v).unwrap();
-                    if a.chars().nth(0).unwrap() == 't'
-                        || b.chars().nth(0).unwrap() == 't'
-                        || c.chars().nth(0).unwrap() == 't'
-                    {
+                    if a.starts_with('t') || b.starts_with('t') || c.starts_with('t') {
                         count += 1;
                     }
                 }
@@ -102,19 +89,19 @@ fn is_clique(current_set: &BTreeSet<i32>, nodes: &MultiMap<i32, i32>) -> bool {
         }
     }
 
-    return true;
+    true
 }
 
-fn part2(input: &MultiMap<i32, i32>, int_to_string: &HashMap<i32, String>) {
+fn part2(input: &MultiMap<i32, i32>, int_to_string: &HashMap<i32, String>) -> String {
     let mut sizes: Vec<Vec<BTreeSet<i32>>> = Vec::new();
-    let nodes: BTreeSet<i32> = BTreeSet::from_iter(input.iter().map(|(k, _)| k.clone()));
-    sizes.push(Vec::from_iter(
-        nodes.iter().map(|v| BTreeSet::from([v.clone()])),
-    ));
+    let nodes: BTreeSet<i32> = BTreeSet::from_iter(input.iter().map(|(k, _)| *k));
+    sizes.push(Vec::from_iter(nodes.iter().map(|v| BTreeSet::from([*v]))));
 
     let mut current_index = 0;
 
-    println!("size of clique 1 | {}", nodes.len());
+    if DEBUG_PRINT {
+        println!("size of clique 1 | {}", nodes.len());
+    }
     loop {
         let mut new_sets: Vec<BTreeSet<i32>> = Vec::new();
         let current_set = &sizes[current_index];
@@ -122,7 +109,7 @@ fn part2(input: &MultiMap<i32, i32>, int_to_string: &HashMap<i32, String>) {
         for set in current_set.iter() {
             let first = set.iter().next().unwrap();
             let last = set.iter().last().unwrap();
-            let neighbours = input.get_vec(&first).unwrap();
+            let neighbours = input.get_vec(first).unwrap();
 
             for n in neighbours.iter() {
                 if n <= last {
@@ -140,28 +127,35 @@ fn part2(input: &MultiMap<i32, i32>, int_to_string: &HashMap<i32, String>) {
             }
         }
         current_index += 1;
-        if new_sets.len() == 0 {
+        if new_sets.is_empty() {
             break;
         }
-        println!("size of clique {current_size} | {}", new_sets.len());
+        if DEBUG_PRINT {
+            println!("size of clique {current_size} | {}", new_sets.len());
+        }
         sizes.push(new_sets);
     }
 
     let last_set = sizes.get(current_index - 1).unwrap();
-    let set = last_set.get(0).unwrap();
-    let new_set = BTreeSet::from_iter(set.iter().map(|s| int_to_string.get(s).unwrap()));
-    for s in new_set {
-        print!("{s},");
-    }
-    println!();
+    let set = last_set.first().unwrap();
+    let new_set = BTreeSet::from_iter(set.iter().map(|s| int_to_string.get(s).unwrap().as_str()));
+    Vec::from_iter(new_set).join(",")
 }
 
-fn main() {
-    // let (inputs, int_to_string) = parse_input("Example.txt");
-    let (inputs, int_to_string) = parse_input("Input.txt");
+pub struct Day23;
 
-    let p1 = part1(&inputs, &int_to_string);
-    println!("Part 1: {p1}");
+impl Solution for Day23 {
+    type Input = (MultiMap<i32, i32>, HashMap<i32, String>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
 
-    part2(&inputs, &int_to_string);
+    fn part1((input, int_to_string): &Self::Input) -> Result<Answer> {
+        Ok(part1(input, int_to_string).into())
+    }
+
+    fn part2((input, int_to_string): &Self::Input) -> Result<Answer> {
+        Ok(part2(input, int_to_string).into())
+    }
 }
@@ -0,0 +1,169 @@
+use aoc_common::{Answer, Result, Solution};
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use regex::Regex;
+
+const DEBUG_PRINT: bool = false;
+
+#[derive(Debug, PartialEq)]
+pub enum Operation {
+    And,
+    Xor,
+    Or,
+}
+
+pub type Gates = HashMap<String, (String, Operation, String)>;
+
+fn parse_input(s: &str) -> Result<(HashMap<String, bool>, Gates)> {
+    let mut inputs: HashMap<String, bool> = HashMap::new();
+    let mut targets: Gates = HashMap::new();
+
+    let mut passing_inputs = true;
+
+    let input_parse = Regex::new(r"([xy]\d\d): (\d)").unwrap();
+    let target_parse = Regex::new(r"(\w+) (\w+) (\w+) -> (\w+)").unwrap();
+
+    for line in s.split("\n") {
+        if line.is_empty() {
+            passing_inputs = false;
+            continue;
+        }
+
+        if passing_inputs {
+            for (_, [reg, v]) in input_parse.captures_iter(line).map(|s| s.extract()) {
+                inputs.insert(reg.to_string(), v.parse::<i32>().unwrap() != 0);
+            }
+        } else {
+            for (_, [reg1, op, reg2, v]) in target_parse.captures_iter(line).map(|s| s.extract()) {
+                let op_v = match op {
+                    "AND" => Operation::And,
+                    "OR" => Operation::Or,
+                    "XOR" => Operation::Xor,
+                    _ => panic!("Unexecpted"),
+                };
+
+                targets.insert(v.to_string(), (reg1.to_string(), op_v, reg2.to_string()));
+            }
+        }
+    }
+
+    Ok((inputs, targets))
+}
+
+fn calc_value(
+    target: &str,
+    registers: &mut HashMap<String, bool>,
+    targets: &Gates,
+    lookup: &mut HashMap<String, String>,
+) -> bool {
+    if let Some(v) = registers.get(target) {
+        return *v;
+    }
+
+    let (reg1, op, reg2) = targets.get(target).unwrap();
+
+    let (reg1, reg2) = if reg1 < reg2 {
+        (reg1, reg2)
+    } else {
+        (reg2, reg1)
+    };
+
+    let v1 = calc_value(reg1, registers, targets, lookup);
+    let v2 = calc_value(reg2, registers, targets, lookup);
+
+    let l = lookup.clone();
+
+    let r1 = l.get(reg1).unwrap_or(reg1);
+    let r2 = l.get(reg2).unwrap_or(reg2);
+
+    // let (r1, r2) = if r1 < r2 { (r1, r2) } else { (r2, r1) };
+
+    let mut new_target = String::new();
+
+    let str = match op {
+        Operation::And => "AND",
+        Operation::Or => "OR",
+        Operation::Xor => "XOR",
+    };
+
+    if !l.contains_key(target) {
+        if r1[1..] == r2[1..] {
+            if r1[..1] == *"x" && r2[..1] == *"y" {
+                let rv: u64 = r1[1..].parse().unwrap();
+
+                new_target = match op {
+                    Operation::And => format!("A{:02}", rv),
+                    Operation::Or => format!("O{:02}", rv),
+                    Operation::Xor => format!("X{:02}", rv),
+                };
+
+                lookup.insert(target.to_string(), new_target.clone());
+            }
+        } else {
+            new_target = lookup
+                .get(target)
+                .map_or(target, String::as_str)
+                .to_string();
+        }
+    } else {
+        new_target = lookup.get(target).unwrap().to_string();
+    }
+
+    if DEBUG_PRINT {
+        println!("{r1} {str} {r2} = {new_target} [{target}]");
+    }
+
+    let final_value = match op {
+        Operation::And => v1 & v2,
+        Operation::Or => v1 | v2,
+        Operation::Xor => v1 ^ v2,
+    };
+
+    registers.insert(target.to_string(), final_value);
+
+    final_value
+}
+
+fn calc_output(inputs: &HashMap<String, bool>, targets: &Gates) -> u64 {
+    let mut inputs = inputs.clone();
+
+    let mut lookup: HashMap<String, String> = HashMap::new();
+
+    let mut outputs: BTreeSet<String> = BTreeSet::new();
+    for target in targets.keys() {
+        if target.starts_with('z') {
+            outputs.insert(target.clone());
+        }
+    }
+
+    let mut output: u64 = 0;
+    for (i, o) in (0..).zip(outputs.iter()) {
+        let v = calc_value(o, &mut inputs, targets, &mut lookup);
+        output |= (v as u64) << i;
+
+        if DEBUG_PRINT {
+            println!();
+        }
+    }
+
+    output
+}
+
+fn part1(inputs: &HashMap<String, bool>, targets: &Gates) -> u64 {
+    calc_output(inputs, targets)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = (HashMap<String, bool>, Gates);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((inputs, targets): &Self::Input) -> Result<Answer> {
+        Ok(part1(inputs, targets).into())
+    }
+}
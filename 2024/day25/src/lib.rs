@@ -1,29 +1,16 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
+use aoc_common::{Answer, Result, Solution};
 
-fn parse_input(file_name: &str) -> (Vec<Vec<i32>>, Vec<Vec<i32>>) {
-    let path = Path::new(file_name);
-    let display = path.display();
+const DEBUG_PRINT: bool = false;
 
-    let mut file = match File::open(path) {
-        Err(err) => panic!("Couldn't open {}: {}", display, err),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-
-    if file.read_to_string(&mut s).is_err() {
-        panic!("Couldn't read {}", file_name);
-    }
+pub type Heights = Vec<i32>;
 
+fn parse_input(s: &str) -> Result<(Vec<Heights>, Vec<Heights>)> {
     let mut keys: Vec<Vec<i32>> = Vec::new();
     let mut locks: Vec<Vec<i32>> = Vec::new();
 
     let mut is_key = false;
     let mut started = false;
-    let mut current: Vec<i32> = Vec::new();
-    current.resize(5, 0);
+    let mut current: Vec<i32> = vec![0; 5];
 
     for line in s.split("\n") {
         if line.is_empty() {
@@ -41,11 +28,7 @@ fn parse_input(file_name: &str) -> (Vec<Vec<i32>>, Vec<Vec<i32>>) {
         }
 
         if !started {
-            if line.chars().nth(0).unwrap() == '.' {
-                is_key = true;
-            } else {
-                is_key = false;
-            }
+            is_key = line.starts_with('.');
 
             started = true;
         } else {
@@ -57,13 +40,15 @@ fn parse_input(file_name: &str) -> (Vec<Vec<i32>>, Vec<Vec<i32>>) {
         }
     }
 
-    println!("Keys: {keys:?}");
-    println!("Locks: {locks:?}");
+    if DEBUG_PRINT {
+        println!("Keys: {keys:?}");
+        println!("Locks: {locks:?}");
+    }
 
-    (keys, locks)
+    Ok((keys, locks))
 }
 
-fn part1(keys: &Vec<Vec<i32>>, locks: &Vec<Vec<i32>>) -> u64 {
+fn part1(keys: &[Vec<i32>], locks: &[Vec<i32>]) -> u64 {
     let mut sum: u64 = 0;
     for key in keys {
         for lock in locks {
@@ -84,10 +69,16 @@ fn part1(keys: &Vec<Vec<i32>>, locks: &Vec<Vec<i32>>) -> u64 {
     sum
 }
 
-fn main() {
-    // let (keys, locks) = parse_input("Example.txt");
-    let (keys, locks) = parse_input("Input.txt");
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (Vec<Heights>, Vec<Heights>);
 
-    let p1 = part1(&keys, &locks);
-    println!("Part 1: {p1}");
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((keys, locks): &Self::Input) -> Result<Answer> {
+        Ok(part1(keys, locks).into())
+    }
 }
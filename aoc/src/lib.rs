@@ -0,0 +1,82 @@
+use aoc_common::solution::run;
+use aoc_common::Runner;
+
+use std::path::{Path, PathBuf};
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub run: Runner,
+}
+
+macro_rules! registry {
+    ($($year:literal, $day:literal => $solution:ty;)*) => {
+        pub const DAYS: &[Day] = &[
+            $(Day {
+                year: $year,
+                day: $day,
+                run: run::<$solution>,
+            },)*
+        ];
+    };
+}
+
+registry! {
+    2019,  1 => aoc2019_day01::Day01;
+    2019,  2 => aoc2019_day02::Day02;
+    2019,  3 => aoc2019_day03::Day03;
+    2019,  4 => aoc2019_day04::Day04;
+    2019,  5 => aoc2019_day05::Day05;
+    2019,  6 => aoc2019_day06::Day06;
+    2019,  7 => aoc2019_day07::Day07;
+    2019,  8 => aoc2019_day08::Day08;
+    2019,  9 => aoc2019_day09::Day09;
+    2024,  1 => aoc2024_day01::Day01;
+    2024,  2 => aoc2024_day02::Day02;
+    2024,  3 => aoc2024_day03::Day03;
+    2024,  4 => aoc2024_day04::Day04;
+    2024,  5 => aoc2024_day05::Day05;
+    2024,  6 => aoc2024_day06::Day06;
+    2024,  7 => aoc2024_day07::Day07;
+    2024,  8 => aoc2024_day08::Day08;
+    2024,  9 => aoc2024_day09::Day09;
+    2024, 10 => aoc2024_day10::Day10;
+    2024, 11 => aoc2024_day11::Day11;
+    2024, 12 => aoc2024_day12::Day12;
+    2024, 13 => aoc2024_day13::Day13;
+    2024, 14 => aoc2024_day14::Day14;
+    2024, 15 => aoc2024_day15::Day15;
+    2024, 16 => aoc2024_day16::Day16;
+    2024, 17 => aoc2024_day17::Day17;
+    2024, 18 => aoc2024_day18::Day18;
+    2024, 19 => aoc2024_day19::Day19;
+    2024, 20 => aoc2024_day20::Day20;
+    2024, 21 => aoc2024_day21::Day21;
+    2024, 22 => aoc2024_day22::Day22;
+    2024, 23 => aoc2024_day23::Day23;
+    2024, 24 => aoc2024_day24::Day24;
+    2024, 25 => aoc2024_day25::Day25;
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+/// The day's crate directory, e.g. `2024/day17`, which is also where its inputs live.
+pub fn day_dir(year: u32, day: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(year.to_string())
+        .join(format!("day{:02}", day))
+}
+
+/// `Input.txt` in the day's directory, or the example `name` (the `.txt` is optional).
+pub fn input_path(year: u32, day: u32, example: Option<&str>) -> PathBuf {
+    let dir = day_dir(year, day);
+    match example {
+        None => dir.join("Input.txt"),
+        Some(name) if Path::new(name).extension().is_some() => dir.join(name),
+        Some(name) => dir.join(format!("{}.txt", name)),
+    }
+}
@@ -0,0 +1,179 @@
+use aoc::Day;
+use aoc_common::{read_input, Params, Part, Result};
+
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::time::Instant;
+
+const USAGE: &str = "\
+Usage: aoc run --year <YEAR> (--day <DAY> | --all) [--part <1|2>]
+               [--input <PATH> | --example <NAME>] [--<PARAM> <VALUE>...]
+
+  --input -        read the puzzle input from stdin
+  --example NAME   use NAME.txt from the day's directory instead of Input.txt
+  --<PARAM>        puzzle parameters, e.g. `--width 11 --height 7` for 2024 day 14";
+
+#[derive(Debug, Default)]
+struct Args {
+    year: Option<u32>,
+    day: Option<u32>,
+    all: bool,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    example: Option<String>,
+    params: Params,
+}
+
+fn parse_args(args: &[String]) -> std::result::Result<Args, String> {
+    let mut iter = args.iter();
+    match iter.next().map(String::as_str) {
+        Some("run") => {}
+        Some(command) => return Err(format!("Unknown command {command}")),
+        None => return Err("Missing command".to_string()),
+    }
+
+    let mut parsed = Args::default();
+    while let Some(flag) = iter.next() {
+        if flag == "--all" {
+            parsed.all = true;
+            continue;
+        }
+
+        let value = iter
+            .next()
+            .ok_or_else(|| format!("Missing value for {flag}"))?;
+        let invalid = || format!("Invalid value for {flag}: {value}");
+        match flag.as_str() {
+            "--year" => parsed.year = Some(value.parse().map_err(|_| invalid())?),
+            "--day" => parsed.day = Some(value.parse().map_err(|_| invalid())?),
+            "--part" => parsed.part = Some(value.parse().map_err(|_| invalid())?),
+            "--input" => parsed.input = Some(PathBuf::from(value)),
+            "--example" => parsed.example = Some(value.clone()),
+            _ => match flag.strip_prefix("--") {
+                Some(name) if !name.is_empty() => parsed.params.set(name, value.as_str()),
+                _ => return Err(format!("Unexpected argument {flag}")),
+            },
+        }
+    }
+
+    if parsed.all == parsed.day.is_some() {
+        return Err("Expected exactly one of --day or --all".to_string());
+    }
+    if parsed.input.is_some() && parsed.example.is_some() {
+        return Err("--input can't be combined with --example".to_string());
+    }
+    if parsed.all && parsed.input.is_some() {
+        return Err("--input can't be combined with --all".to_string());
+    }
+    // Examples and parameters belong to one day; the others would quietly
+    // give wrong answers with them.
+    if parsed.all && parsed.example.is_some() {
+        return Err("--example can't be combined with --all".to_string());
+    }
+    if parsed.all && !parsed.params.is_empty() {
+        return Err("Puzzle parameters can't be combined with --all".to_string());
+    }
+    if !parsed.all && parsed.year.is_none() {
+        return Err("Missing --year".to_string());
+    }
+
+    Ok(parsed)
+}
+
+/// Prints each part's answer, or why it failed. Returns whether every part
+/// was answered.
+fn run_day(day: &Day, input: &str, params: &Params, parts: &[Part]) -> Result<bool> {
+    let start = Instant::now();
+    let answers = (day.run)(input, params, parts)?;
+    let elapsed = start.elapsed();
+
+    let mut answered = true;
+    for (part, answer) in parts.iter().zip(answers) {
+        let answer = match answer {
+            Ok(answer) => answer.to_string(),
+            Err(e) => {
+                println!("{} day {:02} part {part} failed: {e}", day.year, day.day);
+                answered = false;
+                continue;
+            }
+        };
+        if answer.contains('\n') {
+            println!("{} day {:02} part {part}:\n{answer}", day.year, day.day);
+        } else {
+            println!("{} day {:02} part {part}: {answer}", day.year, day.day);
+        }
+    }
+    println!("{} day {:02} took {elapsed:.2?}", day.year, day.day);
+
+    Ok(answered)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = parse_args(&args).unwrap_or_else(|msg| {
+        eprintln!("{msg}");
+        eprintln!("{USAGE}");
+        process::exit(2);
+    });
+
+    if let Err(e) = run(args) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+fn run(args: Args) -> Result<()> {
+    let parts: Vec<Part> = match args.part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+
+    if args.all {
+        let mut failed = false;
+        for day in aoc::DAYS {
+            if args.year.is_some_and(|year| year != day.year) {
+                continue;
+            }
+
+            let path = aoc::input_path(day.year, day.day, None);
+            if !path.exists() {
+                println!(
+                    "{} day {:02} skipped: no {}",
+                    day.year,
+                    day.day,
+                    path.display()
+                );
+                continue;
+            }
+
+            match run_day(day, &read_input(&path)?, &Params::new(), &parts) {
+                Ok(answered) => failed |= !answered,
+                Err(e) => {
+                    println!("{} day {:02} failed: {e}", day.year, day.day);
+                    failed = true;
+                }
+            }
+        }
+
+        if failed {
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let (year, day_number) = (args.year.unwrap(), args.day.unwrap());
+    let Some(day) = aoc::find(year, day_number) else {
+        eprintln!("No solution for {year} day {day_number}");
+        process::exit(1);
+    };
+
+    let path = args
+        .input
+        .unwrap_or_else(|| aoc::input_path(year, day_number, args.example.as_deref()));
+    if !run_day(day, &read_input(path)?, &args.params, &parts)? {
+        process::exit(1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+//! Runs every day against the answers recorded in its `answers.toml`.
+//!
+//! Each table in the file is named after an input file in the day's directory
+//! (`[Example]` is `Example.txt`) and holds the expected `part1`/`part2`,
+//! plus optional puzzle `params`. An `[Input]` table is only checked when
+//! `Input.txt` exists, since the real inputs aren't checked in.
+
+use aoc_common::{read_input, Params, Part};
+
+use std::fs;
+
+use toml::{Table, Value};
+
+fn to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn check(year: u32, day: u32) {
+    let solution = aoc::find(year, day).expect("Day isn't registered");
+    let answers_path = aoc::day_dir(year, day).join("answers.toml");
+    let answers: Table = fs::read_to_string(&answers_path)
+        .unwrap_or_else(|e| panic!("Couldn't read {}: {e}", answers_path.display()))
+        .parse()
+        .unwrap_or_else(|e| panic!("Couldn't parse {}: {e}", answers_path.display()));
+
+    let mut failures: Vec<String> = Vec::new();
+
+    for (name, expected) in answers.iter() {
+        let path = aoc::input_path(year, day, (name != "Input").then_some(name.as_str()));
+        if name == "Input" && !path.exists() {
+            continue;
+        }
+
+        let mut params = Params::new();
+        if let Some(Value::Table(values)) = expected.get("params") {
+            for (key, value) in values {
+                params.set(key.as_str(), to_string(value));
+            }
+        }
+
+        let mut parts: Vec<Part> = Vec::new();
+        let mut wanted: Vec<String> = Vec::new();
+        for part in Part::BOTH {
+            if let Some(value) = expected.get(format!("part{part}")) {
+                parts.push(part);
+                wanted.push(to_string(value));
+            }
+        }
+
+        let input = read_input(&path).unwrap_or_else(|e| panic!("{e}"));
+        let answers = match (solution.run)(&input, &params, &parts) {
+            Ok(answers) => answers,
+            Err(e) => {
+                failures.push(format!("{name}: {e}"));
+                continue;
+            }
+        };
+
+        for ((part, answer), wanted) in parts.iter().zip(answers).zip(wanted) {
+            let answer = match answer {
+                Ok(answer) => answer.to_string(),
+                Err(e) => {
+                    failures.push(format!("{name} part {part}: {e}"));
+                    continue;
+                }
+            };
+            if answer != wanted {
+                failures.push(format!(
+                    "{name} part {part}: expected {wanted:?}, got {answer:?}"
+                ));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{year} day {day:02}:\n{}",
+        failures.join("\n")
+    );
+}
+
+macro_rules! answers {
+    ($($test:ident: $year:literal, $day:literal;)*) => {
+        $(
+            #[test]
+            fn $test() {
+                check($year, $day);
+            }
+        )*
+    };
+}
+
+answers! {
+    y2019_day01: 2019, 1;
+    y2019_day02: 2019, 2;
+    y2019_day03: 2019, 3;
+    y2019_day05: 2019, 5;
+    y2019_day06: 2019, 6;
+    y2019_day07: 2019, 7;
+    y2019_day08: 2019, 8;
+    y2019_day09: 2019, 9;
+    y2024_day01: 2024, 1;
+    y2024_day02: 2024, 2;
+    y2024_day03: 2024, 3;
+    y2024_day04: 2024, 4;
+    y2024_day05: 2024, 5;
+    y2024_day06: 2024, 6;
+    y2024_day07: 2024, 7;
+    y2024_day08: 2024, 8;
+    y2024_day09: 2024, 9;
+    y2024_day10: 2024, 10;
+    y2024_day11: 2024, 11;
+    y2024_day12: 2024, 12;
+    y2024_day13: 2024, 13;
+    y2024_day14: 2024, 14;
+    y2024_day15: 2024, 15;
+    y2024_day16: 2024, 16;
+    y2024_day17: 2024, 17;
+    y2024_day18: 2024, 18;
+    y2024_day19: 2024, 19;
+    y2024_day20: 2024, 20;
+    y2024_day21: 2024, 21;
+    y2024_day22: 2024, 22;
+    y2024_day23: 2024, 23;
+    y2024_day24: 2024, 24;
+    y2024_day25: 2024, 25;
+}
@@ -0,0 +1,148 @@
+use std::ops::{Add, AddAssign};
+
+use crate::error::Error;
+use crate::vec2::Vec2;
+
+/// One of the four grid directions, with y growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.reverse().turn_right()
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// The arrow `TryFrom<char>` reads back as this direction.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction::Up => Vec2::new(0, -1),
+            Direction::Right => Vec2::new(1, 0),
+            Direction::Down => Vec2::new(0, 1),
+            Direction::Left => Vec2::new(-1, 0),
+        }
+    }
+}
+
+/// Arrows (`^>v<`) or compass points (`NESW`), north being up.
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Direction, Error> {
+        match c {
+            '^' | 'N' => Ok(Direction::Up),
+            '>' | 'E' => Ok(Direction::Right),
+            'v' | 'S' => Ok(Direction::Down),
+            '<' | 'W' => Ok(Direction::Left),
+            _ => Err(Error::parse(format!("Unknown direction {:?}", c))),
+        }
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Vec2 {
+        direction.offset()
+    }
+}
+
+impl Add<Direction> for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, direction: Direction) -> Vec2 {
+        self + direction.offset()
+    }
+}
+
+impl AddAssign<Direction> for Vec2 {
+    fn add_assign(&mut self, direction: Direction) {
+        *self += direction.offset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn four_turns_come_back() {
+        for direction in Direction::ALL {
+            let mut right = direction;
+            let mut left = direction;
+            for _ in 0..4 {
+                right = right.turn_right();
+                left = left.turn_left();
+            }
+            assert_eq!((right, left), (direction, direction));
+            assert_eq!(direction.turn_left().turn_right(), direction);
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    }
+
+    #[test]
+    fn reverse_is_opposite() {
+        for direction in Direction::ALL {
+            assert_ne!(direction.reverse(), direction);
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(
+                direction.offset() + direction.reverse().offset(),
+                Vec2::new(0, 0)
+            );
+            assert_eq!(direction.reverse().is_vertical(), direction.is_vertical());
+        }
+    }
+
+    #[test]
+    fn offsets_and_arrows() {
+        assert_eq!(Vec2::from(Direction::Up), Vec2::new(0, -1));
+        assert_eq!(Vec2::from(Direction::Right), Vec2::new(1, 0));
+
+        let mut pos = Vec2::new(2, 2) + Direction::Down;
+        pos += Direction::Left;
+        assert_eq!(pos, Vec2::new(1, 3));
+
+        for direction in Direction::ALL {
+            assert_eq!(Direction::try_from(direction.arrow()).unwrap(), direction);
+        }
+        assert_eq!(Direction::try_from('W').unwrap(), Direction::Left);
+        assert!(Direction::try_from('x').is_err());
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+use std::path::PathBuf;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Io { path: PathBuf, source: io::Error },
+    Parse(String),
+    Param(String),
+    Solve(String),
+}
+
+impl Error {
+    pub fn parse(message: impl Into<String>) -> Error {
+        Error::Parse(message.into())
+    }
+
+    pub fn solve(message: impl Into<String>) -> Error {
+        Error::Solve(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "Couldn't read {}: {}", path.display(), source),
+            Error::Parse(message) => write!(f, "Couldn't parse input: {}", message),
+            Error::Param(message) => write!(f, "Bad parameter: {}", message),
+            Error::Solve(message) => write!(f, "Couldn't solve: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(_) | Error::Param(_) | Error::Solve(_) => None,
+        }
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Error {
+        Error::Parse(err.to_string())
+    }
+}
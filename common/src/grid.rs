@@ -0,0 +1,280 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::direction::Direction;
+use crate::error::{Error, Result};
+use crate::vec2::Vec2;
+
+/// Clockwise from up.
+const NEIGHBORS8: [Vec2; 8] = [
+    Vec2::new(0, -1),
+    Vec2::new(1, -1),
+    Vec2::new(1, 0),
+    Vec2::new(1, 1),
+    Vec2::new(0, 1),
+    Vec2::new(-1, 1),
+    Vec2::new(-1, 0),
+    Vec2::new(-1, -1),
+];
+
+/// A rectangular map, stored row by row. Lookups outside it give `None`
+/// through `get`, and panic through indexing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: i32,
+    height: i32,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Fails for a negative size, or one too big to index.
+    pub fn new(width: i32, height: i32, fill: T) -> Result<Grid<T>>
+    where
+        T: Clone,
+    {
+        let len = match width.checked_mul(height) {
+            Some(len) if width >= 0 && height >= 0 => len as usize,
+            _ => {
+                return Err(Error::parse(format!(
+                    "Invalid grid size {}x{}",
+                    width, height
+                )))
+            }
+        };
+
+        Ok(Grid {
+            width,
+            height,
+            cells: vec![fill; len],
+        })
+    }
+
+    /// Parses one cell per character, skipping empty lines. Every row has
+    /// to be the same length.
+    pub fn parse_with(s: &str, mut cell: impl FnMut(char) -> Result<T>) -> Result<Grid<T>> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in (0_i32..).zip(crate::input::lines(s)) {
+            let row = line.chars().map(&mut cell).collect::<Result<Vec<T>>>()?;
+            if y == 0 {
+                width = row.len() as i32;
+            } else if row.len() as i32 != width {
+                return Err(Error::parse(format!(
+                    "Row {} is {} wide, expected {}",
+                    y,
+                    row.len(),
+                    width
+                )));
+            }
+            cells.extend(row);
+            height = y + 1;
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: &Vec2) -> bool {
+        pos.x >= 0 && pos.x < self.width && pos.y >= 0 && pos.y < self.height
+    }
+
+    fn index_of(&self, pos: &Vec2) -> Option<usize> {
+        self.in_bounds(pos)
+            .then(|| (pos.y * self.width + pos.x) as usize)
+    }
+
+    pub fn get(&self, pos: &Vec2) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: &Vec2) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Vec2::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The orthogonal neighbors of `pos` that are inside the grid.
+    pub fn neighbors4(&self, pos: &Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        let pos = *pos;
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| pos + direction)
+            .filter(|next| self.in_bounds(next))
+    }
+
+    /// Like `neighbors4`, including diagonals.
+    pub fn neighbors8(&self, pos: &Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        let pos = *pos;
+        NEIGHBORS8
+            .iter()
+            .map(move |offset| pos + *offset)
+            .filter(|next| self.in_bounds(next))
+    }
+
+    /// The first position holding `value`, row by row.
+    pub fn find(&self, value: T) -> Option<Vec2>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| **cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(s: &str) -> Result<Grid<char>> {
+        Grid::parse_with(s, Ok)
+    }
+}
+
+impl<T> Index<Vec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2) -> &T {
+        match self.index_of(&pos) {
+            Some(i) => &self.cells[i],
+            None => panic!("{:?} is outside the grid", pos),
+        }
+    }
+}
+
+impl<T> IndexMut<Vec2> for Grid<T> {
+    fn index_mut(&mut self, pos: Vec2) -> &mut T {
+        match self.index_of(&pos) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{:?} is outside the grid", pos),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1) as usize) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(positions: impl Iterator<Item = Vec2>) -> Vec<Vec2> {
+        let mut positions: Vec<Vec2> = positions.collect();
+        positions.sort();
+        positions
+    }
+
+    #[test]
+    fn parses_rows() {
+        let grid = Grid::parse("ab\ncd\n\nef\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[Vec2::new(1, 2)], 'f');
+        assert_eq!(grid.to_string(), "ab\ncd\nef\n");
+
+        assert!(Grid::parse("abc\nde").is_err());
+        let digits = Grid::parse_with("12\n3x", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| Error::parse(format!("Not a digit {:?}", c)))
+        });
+        assert!(digits.is_err());
+    }
+
+    #[test]
+    fn rejects_bad_sizes() {
+        assert!(Grid::new(-5, 3, 0).is_err());
+        assert!(Grid::new(3, -5, 0).is_err());
+        assert!(Grid::new(i32::MAX, i32::MAX, 0).is_err());
+        assert_eq!(Grid::new(0, 0, 0).unwrap().iter().count(), 0);
+    }
+
+    #[test]
+    fn lookups_outside() {
+        let mut grid = Grid::new(3, 2, 0).unwrap();
+        for pos in [Vec2::new(-1, 0), Vec2::new(3, 0), Vec2::new(0, 2)] {
+            assert!(!grid.in_bounds(&pos));
+            assert_eq!(grid.get(&pos), None);
+            assert_eq!(grid.get_mut(&pos), None);
+        }
+
+        grid[Vec2::new(2, 1)] = 7;
+        assert_eq!(grid.get(&Vec2::new(2, 1)), Some(&7));
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the grid")]
+    fn indexing_outside_panics() {
+        let grid = Grid::new(3, 2, 0).unwrap();
+        let _ = grid[Vec2::new(0, -1)];
+    }
+
+    #[test]
+    fn neighbors_at_edges() {
+        let grid = Grid::new(3, 3, ()).unwrap();
+
+        assert_eq!(
+            sorted(grid.neighbors4(&Vec2::new(0, 0))),
+            [Vec2::new(0, 1), Vec2::new(1, 0)]
+        );
+        assert_eq!(
+            sorted(grid.neighbors8(&Vec2::new(2, 0))),
+            [Vec2::new(1, 0), Vec2::new(1, 1), Vec2::new(2, 1)]
+        );
+        assert_eq!(grid.neighbors4(&Vec2::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8(&Vec2::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn iterates_row_by_row() {
+        let grid = Grid::parse("ab\ncd").unwrap();
+        let cells: Vec<(Vec2, char)> = grid.iter().map(|(pos, c)| (pos, *c)).collect();
+        assert_eq!(
+            cells,
+            [
+                (Vec2::new(0, 0), 'a'),
+                (Vec2::new(1, 0), 'b'),
+                (Vec2::new(0, 1), 'c'),
+                (Vec2::new(1, 1), 'd'),
+            ]
+        );
+        assert_eq!(grid.find('c'), Some(Vec2::new(0, 1)));
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).find('D'),
+            Some(Vec2::new(1, 1))
+        );
+    }
+}
@@ -0,0 +1,213 @@
+//! Saving grids and point sets as pictures: a PNG for a single state, or an
+//! animated GIF for a sequence of them. Only built with the `image` feature.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::rc::Rc;
+use std::time::Duration;
+
+use ::image::codecs::gif::{GifEncoder, Repeat};
+use ::image::{Delay, Frame, ImageResult, Rgba, RgbaImage};
+
+use crate::grid::Grid;
+use crate::vec2::Vec2;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// The colour of each kind of cell, falling back to a default for the rest.
+#[derive(Debug, Clone)]
+pub struct Palette<T> {
+    colors: HashMap<T, Rgb>,
+    default: Rgb,
+}
+
+impl<T: Eq + Hash> Palette<T> {
+    pub fn new(default: Rgb) -> Palette<T> {
+        Palette {
+            colors: HashMap::new(),
+            default,
+        }
+    }
+
+    pub fn with(mut self, cell: T, color: Rgb) -> Palette<T> {
+        self.colors.insert(cell, color);
+        self
+    }
+
+    pub fn color(&self, cell: &T) -> Rgb {
+        self.colors.get(cell).copied().unwrap_or(self.default)
+    }
+}
+
+/// A picture with a square of `scale` by `scale` pixels per cell.
+#[derive(Debug, Clone)]
+pub struct Picture {
+    image: RgbaImage,
+    scale: u32,
+}
+
+impl Picture {
+    /// A `width` x `height` cell picture filled with `background`. Panics if
+    /// that many pixels don't fit in an image.
+    pub fn blank(width: i32, height: i32, scale: u32, background: Rgb) -> Picture {
+        let [r, g, b] = background;
+        let pixels = |cells: i32| {
+            (cells.max(0) as u32)
+                .checked_mul(scale)
+                .expect("Picture too large")
+        };
+        Picture {
+            image: RgbaImage::from_pixel(pixels(width), pixels(height), Rgba([r, g, b, 255])),
+            scale,
+        }
+    }
+
+    pub fn from_grid<T: Eq + Hash>(grid: &Grid<T>, palette: &Palette<T>, scale: u32) -> Picture {
+        let mut picture = Picture::blank(grid.width(), grid.height(), scale, palette.default);
+        for (pos, cell) in grid.iter() {
+            picture.fill(pos, palette.color(cell));
+        }
+        picture
+    }
+
+    /// Paints `points` over the picture, skipping any outside it.
+    pub fn mark(mut self, points: impl IntoIterator<Item = Vec2>, color: Rgb) -> Picture {
+        for pos in points {
+            self.fill(pos, color);
+        }
+        self
+    }
+
+    fn fill(&mut self, pos: Vec2, [r, g, b]: Rgb) {
+        let corner = |cell: i32| u32::try_from(cell).ok()?.checked_mul(self.scale);
+        let (Some(left), Some(top)) = (corner(pos.x), corner(pos.y)) else {
+            return;
+        };
+        let (width, height) = self.image.dimensions();
+        if left >= width || top >= height {
+            return;
+        }
+        for y in top..top.saturating_add(self.scale).min(height) {
+            for x in left..left.saturating_add(self.scale).min(width) {
+                self.image.put_pixel(x, y, Rgba([r, g, b, 255]));
+            }
+        }
+    }
+
+    pub fn save_png(&self, path: impl AsRef<Path>) -> ImageResult<()> {
+        self.image.save(path)
+    }
+}
+
+/// Pictures played one after another, looping forever. They should all be
+/// the same size. Each frame is encoded into the file as it's pushed, so
+/// long animations don't pile up in memory.
+pub struct Gif {
+    encoder: GifEncoder<Shared>,
+    out: Rc<RefCell<BufWriter<File>>>,
+    delay: Delay,
+    frames: usize,
+}
+
+/// The encoder's handle on the file. It keeps its writer until dropped, so
+/// `Gif::finish` flushes through its own handle to see any error.
+struct Shared(Rc<RefCell<BufWriter<File>>>);
+
+impl Write for Shared {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.borrow_mut().flush()
+    }
+}
+
+impl Gif {
+    pub fn create(path: impl AsRef<Path>, delay: Duration) -> ImageResult<Gif> {
+        let out = Rc::new(RefCell::new(BufWriter::new(File::create(path)?)));
+        let mut encoder = GifEncoder::new(Shared(Rc::clone(&out)));
+        encoder.set_repeat(Repeat::Infinite)?;
+        Ok(Gif {
+            encoder,
+            out,
+            delay: Delay::from_saturating_duration(delay),
+            frames: 0,
+        })
+    }
+
+    pub fn push(&mut self, picture: Picture) -> ImageResult<()> {
+        self.encoder
+            .encode_frame(Frame::from_parts(picture.image, 0, 0, self.delay))?;
+        self.frames += 1;
+        Ok(())
+    }
+
+    /// The number of frames pushed so far.
+    pub fn len(&self) -> usize {
+        self.frames
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// Ends the file. Dropping the `Gif` does too, but can't report errors.
+    pub fn finish(self) -> ImageResult<()> {
+        let Gif { encoder, out, .. } = self;
+        drop(encoder);
+        out.borrow_mut().flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_only_inside() {
+        let picture = Picture::blank(2, 2, 3, BLACK).mark(
+            [
+                Vec2::new(1, 0),
+                Vec2::new(-1, 0),
+                Vec2::new(2, 0),
+                Vec2::new(i32::MAX, i32::MAX),
+            ],
+            WHITE,
+        );
+        assert_eq!(picture.image.dimensions(), (6, 6));
+        let white: Vec<(u32, u32)> = picture
+            .image
+            .enumerate_pixels()
+            .filter(|(_, _, pixel)| pixel.0 == [255, 255, 255, 255])
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        assert_eq!(white.len(), 9);
+        assert!(white.iter().all(|&(x, y)| (3..6).contains(&x) && y < 3));
+    }
+
+    #[test]
+    fn gif_streams_frames() {
+        let path = std::env::temp_dir().join(format!("aoc-gif-{}.gif", std::process::id()));
+        let mut gif = Gif::create(&path, Duration::from_millis(10)).unwrap();
+        assert!(gif.is_empty());
+        for color in [BLACK, WHITE, BLACK] {
+            gif.push(Picture::blank(2, 2, 1, color)).unwrap();
+        }
+        assert_eq!(gif.len(), 3);
+        gif.finish().unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(bytes.starts_with(b"GIF89a"));
+        assert_eq!(bytes.last(), Some(&b';'));
+    }
+}
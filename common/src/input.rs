@@ -0,0 +1,27 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+use crate::error::{Error, Result};
+
+/// Reads the whole puzzle input, with `-` meaning stdin.
+pub fn read_input(file_name: impl AsRef<Path>) -> Result<String> {
+    let path = file_name.as_ref();
+
+    let result = if path == Path::new("-") {
+        let mut s = String::new();
+        io::stdin().read_to_string(&mut s).map(|_| s)
+    } else {
+        fs::read_to_string(path)
+    };
+
+    result.map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Iterates over the lines of `s`, skipping empty ones.
+pub fn lines(s: &str) -> impl Iterator<Item = &str> {
+    s.split('\n').filter(|line| !line.is_empty())
+}
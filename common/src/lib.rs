@@ -0,0 +1,20 @@
+pub mod direction;
+pub mod error;
+pub mod grid;
+#[cfg(feature = "image")]
+pub mod image;
+pub mod input;
+pub mod params;
+pub mod render;
+pub mod search;
+pub mod solution;
+pub mod vec2;
+
+pub use direction::Direction;
+pub use error::{Error, Result};
+pub use grid::Grid;
+pub use input::{lines, read_input};
+pub use params::Params;
+pub use search::{astar, bfs, dijkstra, Search};
+pub use solution::{Answer, Part, Runner, Solution};
+pub use vec2::Vec2;
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+/// Named puzzle parameters that differ between the real input and the
+/// examples, e.g. day14's floor size. Passed on the command line as
+/// `--name value`.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    values: HashMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(name.into(), value.into());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Fails on the first parameter that isn't one of `accepted`, so a typo
+    /// doesn't quietly fall back to the default.
+    pub fn check(&self, accepted: &[&str]) -> Result<()> {
+        let mut names: Vec<&String> = self.values.keys().collect();
+        names.sort();
+        match names
+            .into_iter()
+            .find(|name| !accepted.contains(&name.as_str()))
+        {
+            None => Ok(()),
+            Some(name) if accepted.is_empty() => {
+                Err(Error::Param(format!("--{} isn't used by this day", name)))
+            }
+            Some(name) => Err(Error::Param(format!(
+                "--{} isn't used by this day, which takes --{}",
+                name,
+                accepted.join(", --")
+            ))),
+        }
+    }
+
+    /// The parameter `name` parsed as a `T`, or `default` if it wasn't given.
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> Result<T> {
+        match self.values.get(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| Error::Param(format!("--{} {}", name, value))),
+            None => Ok(default),
+        }
+    }
+}
@@ -0,0 +1,351 @@
+//! Drawing grids in the terminal with ANSI colours, and redrawing them in
+//! place to animate a walk.
+
+use std::fmt;
+use std::io::{self, Stdout, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::grid::Grid;
+use crate::vec2::Vec2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Colours that tell apart well next to each other, for regions.
+    pub const PALETTE: [Color; 6] = [
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+    ];
+
+    /// The `n`th colour of the palette, wrapping around.
+    pub fn nth(n: usize) -> Color {
+        Color::PALETTE[n % Color::PALETTE.len()]
+    }
+
+    /// The SGR code, as a foreground colour or, adding 10, as a background.
+    fn code(self, background: bool) -> String {
+        let offset = if background { 10 } else { 0 };
+        match self {
+            Color::Rgb(r, g, b) => format!("{};2;{};{};{}", 38 + offset, r, g, b),
+            Color::Gray => (90 + offset).to_string(),
+            basic => (30 + offset + basic.index()).to_string(),
+        }
+    }
+
+    fn index(self) -> u8 {
+        match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            _ => 7,
+        }
+    }
+}
+
+/// How to draw a cell. Anything left `None` shows what's underneath.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub glyph: Option<char>,
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+}
+
+impl Style {
+    pub fn glyph(glyph: char) -> Style {
+        Style {
+            glyph: Some(glyph),
+            ..Style::default()
+        }
+    }
+
+    pub fn fg(color: Color) -> Style {
+        Style {
+            fg: Some(color),
+            ..Style::default()
+        }
+    }
+
+    pub fn bg(color: Color) -> Style {
+        Style {
+            bg: Some(color),
+            ..Style::default()
+        }
+    }
+
+    pub fn with_glyph(self, glyph: char) -> Style {
+        Style {
+            glyph: Some(glyph),
+            ..self
+        }
+    }
+
+    pub fn with_fg(self, color: Color) -> Style {
+        Style {
+            fg: Some(color),
+            ..self
+        }
+    }
+
+    pub fn with_bg(self, color: Color) -> Style {
+        Style {
+            bg: Some(color),
+            ..self
+        }
+    }
+
+    /// This style drawn on top of `below`.
+    pub fn over(self, below: Style) -> Style {
+        Style {
+            glyph: self.glyph.or(below.glyph),
+            fg: self.fg.or(below.fg),
+            bg: self.bg.or(below.bg),
+        }
+    }
+
+    fn escape(self) -> String {
+        let codes: Vec<String> = [
+            self.fg.map(|c| c.code(false)),
+            self.bg.map(|c| c.code(true)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        format!("\x1b[0;{}m", codes.join(";"))
+    }
+}
+
+/// A grid ready to print, with overlays stacked on top of its cells. Later
+/// overlays cover earlier ones, so add a path before the robot walking it.
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    cell: Box<dyn Fn(&T) -> Style + 'a>,
+    overlay: Grid<Style>,
+    colour: bool,
+}
+
+impl<'a, T> Render<'a, T> {
+    /// Draws each cell as `cell` says. Cells without a glyph show a space.
+    pub fn new(grid: &'a Grid<T>, cell: impl Fn(&T) -> Style + 'a) -> Render<'a, T> {
+        Render {
+            grid,
+            cell: Box::new(cell),
+            overlay: grid.map(|_| Style::default()),
+            colour: true,
+        }
+    }
+
+    /// Draws `style` over `positions`, skipping any outside the grid.
+    pub fn overlay(self, positions: impl IntoIterator<Item = Vec2>, style: Style) -> Self {
+        self.overlay_with(positions.into_iter().map(|pos| (pos, style)))
+    }
+
+    /// Like `overlay`, with a style per position.
+    pub fn overlay_with(mut self, positions: impl IntoIterator<Item = (Vec2, Style)>) -> Self {
+        for (pos, style) in positions {
+            if let Some(below) = self.overlay.get_mut(&pos) {
+                *below = style.over(*below);
+            }
+        }
+        self
+    }
+
+    /// Colours each group of positions with the next colour of the palette.
+    pub fn regions<R>(mut self, regions: impl IntoIterator<Item = R>) -> Self
+    where
+        R: IntoIterator<Item = Vec2>,
+    {
+        for (i, region) in regions.into_iter().enumerate() {
+            self = self.overlay(region, Style::bg(Color::nth(i)));
+        }
+        self
+    }
+
+    /// Leaves out the escape codes, keeping only the glyphs.
+    pub fn plain(mut self) -> Self {
+        self.colour = false;
+        self
+    }
+}
+
+impl<'a> Render<'a, char> {
+    /// Draws every character as it is.
+    pub fn chars(grid: &'a Grid<char>) -> Render<'a, char> {
+        Render::new(grid, |c| Style::glyph(*c))
+    }
+}
+
+impl<T> fmt::Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.grid.height() {
+            let mut current = (None, None);
+            for x in 0..self.grid.width() {
+                let pos = Vec2::new(x, y);
+                let style = self.overlay[pos].over((self.cell)(&self.grid[pos]));
+                if self.colour && (style.fg, style.bg) != current {
+                    write!(f, "{}", style.escape())?;
+                    current = (style.fg, style.bg);
+                }
+                write!(f, "{}", style.glyph.unwrap_or(' '))?;
+            }
+            if self.colour && current != (None, None) {
+                write!(f, "\x1b[0m")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Redraws frames in place, pausing `delay` after each one. The cursor is
+/// hidden until the animation is dropped.
+pub struct Animation<W: Write> {
+    out: W,
+    delay: Duration,
+    started: bool,
+}
+
+impl Animation<Stdout> {
+    pub fn stdout(delay: Duration) -> Animation<Stdout> {
+        Animation::new(io::stdout(), delay)
+    }
+}
+
+impl<W: Write> Animation<W> {
+    pub fn new(out: W, delay: Duration) -> Animation<W> {
+        Animation {
+            out,
+            delay,
+            started: false,
+        }
+    }
+
+    pub fn frame(&mut self, frame: impl fmt::Display) -> io::Result<()> {
+        if !self.started {
+            // Clear the screen once and hide the cursor.
+            write!(self.out, "\x1b[2J\x1b[?25l")?;
+            self.started = true;
+        }
+        // Home the cursor, draw, and clear whatever the last frame left below.
+        write!(self.out, "\x1b[H{}\x1b[J", frame)?;
+        self.out.flush()?;
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+impl<W: Write> Drop for Animation<W> {
+    fn drop(&mut self) {
+        if self.started {
+            let _ = write!(self.out, "\x1b[?25h");
+            let _ = self.out.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("ab\ncd").unwrap()
+    }
+
+    #[test]
+    fn plain_text() {
+        let grid = grid();
+        assert_eq!(Render::chars(&grid).plain().to_string(), "ab\ncd\n");
+
+        let blank = Render::new(&grid, |c| match c {
+            'a' => Style::glyph('#'),
+            _ => Style::default(),
+        });
+        assert_eq!(blank.plain().to_string(), "# \n  \n");
+    }
+
+    #[test]
+    fn later_overlays_cover_earlier_ones() {
+        let grid = grid();
+        let render = Render::chars(&grid)
+            .overlay([Vec2::new(1, 0), Vec2::new(5, 5)], Style::glyph('o'))
+            .overlay_with([
+                (Vec2::new(1, 0), Style::glyph('@')),
+                (Vec2::new(0, 1), Style::fg(Color::Red)),
+            ])
+            .plain();
+        assert_eq!(render.to_string(), "a@\ncd\n");
+    }
+
+    #[test]
+    fn escapes_only_on_changes() {
+        let grid = grid();
+        let render =
+            Render::chars(&grid).overlay([Vec2::new(0, 0), Vec2::new(1, 1)], Style::fg(Color::Red));
+        assert_eq!(
+            render.to_string(),
+            "\x1b[0;31ma\x1b[0;mb\nc\x1b[0;31md\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn regions_take_palette_colours() {
+        let grid = grid();
+        let render = Render::chars(&grid).regions([vec![Vec2::new(0, 0)], vec![Vec2::new(1, 1)]]);
+        assert_eq!(
+            render.to_string(),
+            "\x1b[0;41ma\x1b[0;mb\nc\x1b[0;42md\x1b[0m\n"
+        );
+        assert_eq!(Color::nth(Color::PALETTE.len()), Color::nth(0));
+    }
+
+    #[test]
+    fn styles_and_codes() {
+        let style = Style::glyph('x').with_bg(Color::Blue);
+        assert_eq!(
+            style.over(Style::fg(Color::Gray).with_glyph('y')),
+            Style {
+                glyph: Some('x'),
+                fg: Some(Color::Gray),
+                bg: Some(Color::Blue),
+            }
+        );
+        assert_eq!(
+            Style::fg(Color::Rgb(1, 2, 3)).with_bg(Color::Gray).escape(),
+            "\x1b[0;38;2;1;2;3;100m"
+        );
+    }
+
+    #[test]
+    fn animation_redraws_in_place() {
+        let mut out = Vec::new();
+        {
+            let mut animation = Animation::new(&mut out, Duration::ZERO);
+            animation.frame("x").unwrap();
+            animation.frame("y").unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[2J\x1b[?25l\x1b[Hx\x1b[J\x1b[Hy\x1b[J\x1b[?25h"
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// What a search found: the shortest distance found so far to every state it
+/// reached, and for each of them every predecessor it can be reached from at
+/// that distance. The predecessors form a DAG holding all of the shortest
+/// paths back to the start.
+///
+/// After a full search every distance is exact. A* stops early, so states it
+/// only saw on the frontier may have a shorter route it never looked for;
+/// only those no further than the closest goal are certain.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    distances: HashMap<S, u64>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new(start: S) -> Search<S> {
+        Search {
+            distances: HashMap::from([(start.clone(), 0)]),
+            predecessors: HashMap::from([(start, Vec::new())]),
+        }
+    }
+
+    /// Records `from -> to` at `distance`. Returns whether `to` got closer,
+    /// in which case it has to be expanded (again).
+    fn relax(&mut self, from: &S, to: S, distance: u64) -> bool {
+        match self.distances.get(&to) {
+            Some(&known) if known < distance => false,
+            Some(&known) if known == distance => {
+                let predecessors = self.predecessors.entry(to).or_default();
+                if !predecessors.contains(from) {
+                    predecessors.push(from.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(to.clone(), distance);
+                self.predecessors.insert(to, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> impl Iterator<Item = (&S, u64)> {
+        self.distances.iter().map(|(state, &d)| (state, d))
+    }
+
+    /// The states one step before `state` on its shortest paths. Empty for
+    /// the start and for states never reached.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from the start to `target`, both included.
+    pub fn path(&self, target: &S) -> Option<Vec<S>> {
+        self.distances.get(target)?;
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state on any shortest path from the start to one of `targets`.
+    pub fn on_shortest_paths(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<S> = targets
+            .into_iter()
+            .filter(|t| self.distances.contains_key(t))
+            .collect();
+        while let Some(state) = stack.pop() {
+            if seen.insert(state.clone()) {
+                stack.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth-first search where every step costs one. Explores everything
+/// reachable from `start`.
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, distance)) = queue.pop_front() {
+        for next in successors(&state) {
+            if search.relax(&state, next.clone(), distance + 1) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm over `(state, cost)` successors. Explores everything
+/// reachable from `start`. Costs must be positive, as for `astar`.
+pub fn dijkstra<S, I>(start: S, successors: impl FnMut(&S) -> I) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, successors, |_| 0, |_| false)
+}
+
+/// A* towards the states matching `is_goal`. Stops once no cheaper route to a
+/// goal is left, having found every shortest path to the closest goals.
+/// `heuristic` must never overestimate, nor drop by more than the cost of a
+/// step.
+///
+/// Panics on a zero cost: a free step could lead back where it came from
+/// and loop the predecessors.
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = BinaryHeap::new();
+    let mut order = 0;
+    let mut best = None;
+    queue.push(Queued {
+        estimate: heuristic(&start),
+        order,
+        distance: 0,
+        state: start,
+    });
+
+    while let Some(Queued {
+        estimate,
+        distance,
+        state,
+        ..
+    }) = queue.pop()
+    {
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+        if search.distance(&state) != Some(distance) {
+            continue;
+        }
+        if is_goal(&state) {
+            best.get_or_insert(distance);
+            continue;
+        }
+
+        for (next, cost) in successors(&state) {
+            assert!(cost > 0, "Search steps must cost something");
+            let next_distance = distance + cost;
+            if search.relax(&state, next.clone(), next_distance) {
+                order += 1;
+                queue.push(Queued {
+                    estimate: next_distance + heuristic(&next),
+                    order,
+                    distance: next_distance,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    search
+}
+
+/// A heap entry, ordered so the lowest estimate pops first, then the one
+/// queued earliest.
+struct Queued<S> {
+    estimate: u64,
+    order: u64,
+    distance: u64,
+    state: S,
+}
+
+impl<S> Ord for Queued<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| other.order.cmp(&self.order))
+    }
+}
+
+impl<S> PartialOrd for Queued<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> PartialEq for Queued<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S> Eq for Queued<S> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small weighted graph: two equally short routes from 0 to 3, a
+    /// longer one through 4, and 5 and 6 off on their own.
+    fn graph(state: &u32) -> Vec<(u32, u64)> {
+        match state {
+            0 => vec![(1, 1), (2, 1), (4, 1)],
+            1 => vec![(3, 1)],
+            2 => vec![(3, 1)],
+            4 => vec![(3, 5)],
+            5 => vec![(6, 1)],
+            _ => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn keeps_tied_paths() {
+        let search = dijkstra(0, graph);
+        assert_eq!(search.distance(&3), Some(2));
+        assert_eq!(search.predecessors(&3), [1, 2]);
+        assert_eq!(search.path(&3), Some(vec![0, 1, 3]));
+        assert_eq!(search.on_shortest_paths([3]), HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn leaves_out_longer_routes() {
+        let search = dijkstra(0, graph);
+        assert_eq!(search.distance(&4), Some(1));
+        assert!(!search.predecessors(&3).contains(&4));
+        assert_eq!(
+            search.on_shortest_paths([3, 4]),
+            HashSet::from([0, 1, 2, 3, 4])
+        );
+        assert!(search.predecessors(&0).is_empty());
+    }
+
+    #[test]
+    fn unreachable_targets() {
+        let search = dijkstra(0, graph);
+        assert_eq!(search.distance(&6), None);
+        assert_eq!(search.path(&6), None);
+        assert!(search.predecessors(&6).is_empty());
+        assert!(search.on_shortest_paths([6]).is_empty());
+        assert_eq!(search.distances().count(), 5);
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        // Right or down across a 3x3 square.
+        let search = bfs((0, 0), |&(x, y)| {
+            [(x + 1, y), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| x <= 2 && y <= 2)
+        });
+        assert_eq!(search.distance(&(2, 2)), Some(4));
+        assert_eq!(search.predecessors(&(1, 1)), [(1, 0), (0, 1)]);
+        assert_eq!(search.on_shortest_paths([(2, 2)]).len(), 9);
+        assert_eq!(search.path(&(1, 2)).map(|path| path.len()), Some(4));
+    }
+
+    #[test]
+    fn astar_stops_at_the_closest_goal() {
+        // A number line where every step costs 1, looking for 3 from 0.
+        let mut expanded = Vec::new();
+        let search = astar(
+            0i32,
+            |&n| {
+                expanded.push(n);
+                [(n - 1, 1), (n + 1, 1)]
+            },
+            |&n| n.abs_diff(3) as u64,
+            |&n| n == 3,
+        );
+
+        assert_eq!(search.path(&3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(expanded, [0, 1, 2]);
+        // Seen from 0 but never expanded, and nothing past the goal.
+        assert_eq!(search.distance(&-1), Some(1));
+        assert_eq!(search.distance(&-2), None);
+        assert_eq!(search.distance(&4), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "must cost something")]
+    fn rejects_free_steps() {
+        dijkstra(0u32, |&n| [((n + 1) % 2, 0)]);
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt;
+
+use crate::error::{Error, Result};
+use crate::params::Params;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(v) => write!(f, "{}", v),
+            Answer::Unsigned(v) => write!(f, "{}", v),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $target:ty, $($source:ty),*) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Answer {
+                    Answer::$variant(<$target>::from(value))
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Signed, i64, i32, i64);
+answer_from!(Unsigned, u64, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Answer {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Answer {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Answer {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl std::str::FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Part> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(Error::parse(format!("Unknown part {}", s))),
+        }
+    }
+}
+
+/// A single day's puzzle: parse the input once, then answer either part from it.
+pub trait Solution {
+    type Input;
+
+    /// The names of the parameters `parse_with` reads. Any other parameter
+    /// is an error.
+    const PARAMS: &'static [&'static str] = &[];
+
+    /// Implement this or `parse_with`; each defaults to the other.
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    /// Days whose answers depend on puzzle parameters override this instead
+    /// of `parse`, and list them in `PARAMS`.
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    /// Fails for puzzles that can go wrong while solving, e.g. a broken
+    /// intcode program.
+    fn part1(input: &Self::Input) -> Result<Answer>;
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+/// Parses once and answers each of `parts`. Only a bad input fails the whole
+/// run; a part that fails leaves its error in place of the answer.
+pub type Runner = fn(&str, &Params, &[Part]) -> Result<Vec<Result<Answer>>>;
+
+pub fn run<S: Solution>(
+    input: &str,
+    params: &Params,
+    parts: &[Part],
+) -> Result<Vec<Result<Answer>>> {
+    params.check(S::PARAMS)?;
+    let parsed = S::parse_with(input, params)?;
+
+    Ok(parts
+        .iter()
+        .map(|part| match part {
+            Part::One => S::part1(&parsed),
+            Part::Two => S::part2(&parsed),
+        })
+        .collect())
+}